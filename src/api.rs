@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::disk_cache;
 use crate::errors::AuthError;
+use crate::sort;
 
 const API: &str = "https://api.spotify.com/v1";
 
@@ -993,7 +994,8 @@ pub async fn get_artist_top_tracks(
         .collect())
 }
 
-/// Albums by an artist, sorted newest-first by `release_date`. We
+/// Albums by an artist, sorted newest-first by `release_date` (see
+/// [`sort::cmp_release`]). We
 /// request `include_groups=album,single` (skip appearances + compilations)
 /// and re-sort client-side because Spotify's default order is not
 /// guaranteed to be by date.
@@ -1043,8 +1045,11 @@ pub async fn get_artist_albums(
             release_date: a.release_date,
         })
         .collect();
-    // Lexicographic sort on `YYYY[-MM[-DD]]` is chronological.
-    albums.sort_by(|a, b| b.release_date.cmp(&a.release_date));
+    // Same-day releases (a single + its album) fall back to title order.
+    albums.sort_by(|a, b| {
+        sort::cmp_release(&b.release_date, &a.release_date)
+            .then_with(|| sort::cmp_text(&a.name, &b.name))
+    });
     Ok(albums)
 }
 
@@ -1212,6 +1217,20 @@ pub fn track_id_from_uri(uri: &str) -> Option<&str> {
     uri.strip_prefix("spotify:track:")
 }
 
+/// Public `open.spotify.com` link for a `spotify:<kind>:<id>` URI — what
+/// "Copy link" puts on the clipboard. `None` for URIs with no web page
+/// (local files) or anything malformed.
+pub fn share_url(uri: &str) -> Option<String> {
+    let mut parts = uri.split(':');
+    let (Some("spotify"), Some(kind), Some(id), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    let shareable = matches!(kind, "track" | "album" | "artist" | "playlist");
+    (shareable && !id.is_empty()).then(|| format!("https://open.spotify.com/{kind}/{id}"))
+}
+
 pub async fn get_currently_playing(token: &str) -> Result<Option<CurrentlyPlaying>, AuthError> {
     #[derive(Deserialize)]
     struct R {
@@ -1585,6 +1604,98 @@ pub async fn get_queue(token: &str) -> Result<Vec<PlaylistTrack>, AuthError> {
         .collect())
 }
 
+/// Catalogue search results — one section per type, best match first.
+#[derive(Debug, Clone, Default)]
+pub struct SearchResults {
+    pub tracks: Vec<PlaylistTrack>,
+    pub artists: Vec<ArtistRef>,
+    pub albums: Vec<AlbumRef>,
+}
+
+impl SearchResults {
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty() && self.artists.is_empty() && self.albums.is_empty()
+    }
+}
+
+/// Search tracks, artists and albums for `query`, up to `limit` of each.
+/// `market=from_token` so unplayable tracks come back flagged. Cached
+/// briefly: retyping a recent query (or backspacing onto it) is instant.
+pub async fn search(token: &str, query: &str, limit: u32) -> Result<SearchResults, AuthError> {
+    #[derive(Deserialize)]
+    struct R {
+        #[serde(default)]
+        tracks: Option<Page<RawTrack>>,
+        #[serde(default)]
+        artists: Option<Page<Artist>>,
+        #[serde(default)]
+        albums: Option<Page<Album>>,
+    }
+    #[derive(Deserialize)]
+    struct Page<T> {
+        // Spotify pads result pages with `null` entries for removed items.
+        #[serde(default)]
+        items: Vec<Option<T>>,
+    }
+    #[derive(Deserialize)]
+    struct Artist {
+        #[serde(default)]
+        id: String,
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        images: Vec<RawImg>,
+    }
+    #[derive(Deserialize)]
+    struct Album {
+        #[serde(default)]
+        id: String,
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        artists: Vec<RawArtist>,
+        #[serde(default)]
+        images: Vec<RawImg>,
+        #[serde(default)]
+        release_date: String,
+    }
+    fn items<T>(page: Option<Page<T>>) -> impl Iterator<Item = T> {
+        page.into_iter().flat_map(|p| p.items).flatten()
+    }
+    let limit = limit.to_string();
+    let url = url::Url::parse_with_params(
+        &format!("{API}/search"),
+        [
+            ("q", query),
+            ("type", "track,artist,album"),
+            ("market", "from_token"),
+            ("limit", limit.as_str()),
+        ],
+    )
+    .map_err(|e| AuthError::Parse(e.to_string()))?;
+    let r: R = get_json(token, url.as_str(), ttl::VOLATILE).await?;
+    Ok(SearchResults {
+        tracks: items(r.tracks).map(RawTrack::into_track).collect(),
+        // Result rows show ~40 px thumbs — the row-size pick is plenty.
+        artists: items(r.artists)
+            .map(|a| ArtistRef {
+                id: a.id,
+                name: a.name,
+                image_url: pick_thumb(&a.images),
+            })
+            .collect(),
+        albums: items(r.albums)
+            .map(|a| AlbumRef {
+                id: a.id,
+                name: a.name,
+                artist: a.artists.into_iter().next().map(|a| a.name).unwrap_or_default(),
+                image_url: pick_thumb(&a.images),
+                release_date: a.release_date,
+            })
+            .collect(),
+    })
+}
+
 /// Set the active device's volume (0..=100). When Opal is the active
 /// device, Spotify routes this back to our Spirc, which adjusts the
 /// SoftMixer — confirmation arrives via the local `VolumeChanged` event.
@@ -1723,6 +1834,21 @@ mod tests {
         assert_eq!(pick_thumb(&t.album.images), None);
     }
 
+    #[test]
+    fn share_url_covers_linkable_uris_only() {
+        assert_eq!(
+            share_url("spotify:track:4uLU6hMCjMI75M1A2tKUQC").as_deref(),
+            Some("https://open.spotify.com/track/4uLU6hMCjMI75M1A2tKUQC")
+        );
+        assert_eq!(
+            share_url("spotify:playlist:37i9").as_deref(),
+            Some("https://open.spotify.com/playlist/37i9")
+        );
+        assert_eq!(share_url("spotify:local:Artist:Album:Song:215"), None);
+        assert_eq!(share_url("spotify:track:"), None);
+        assert_eq!(share_url(LIKED_SONGS_ID), None);
+    }
+
     #[test]
     fn item_resolver_handles_both_wrappers() {
         // Playlist `/items` → `item`; saved `/me/tracks` → `track`. Both map.
@@ -1,10 +1,11 @@
 //! Per-frame tick — the app's frame-loop logic, factored out of `main`.
 //!
-//! Drains the worker (routing each response through the [`reducer`]), runs
-//! the per-domain ticks (canvas node sync + active/dim, debounced prefs
-//! save), applies a pending cache relocation, and hides the dead base
-//! background once the album-art backdrop fully covers it. Pure shell
-//! logic — no view building.
+//! Drains the worker (routing each response through the [`reducer`]) and
+//! the OS media keys + global shortcuts (through the player-bar
+//! dispatcher), runs the per-domain ticks (canvas node sync + active/dim,
+//! debounced prefs save), applies a pending cache relocation, and hides
+//! the dead base background once the album-art backdrop fully covers it.
+//! Pure shell logic — no view building.
 
 use std::cell::Cell;
 use std::rc::Rc;
@@ -16,17 +17,29 @@ use crate::app::AppState;
 use crate::app::cx::Cx;
 use crate::app::reducer;
 use crate::disk_cache;
+use crate::hotkeys::Hotkeys;
+use crate::media_controls::{MediaControls, MediaKey};
+use crate::model::player::{ActionSource, KeyVerdict, PlayerAction, PlayerIntent};
+use crate::views::MainNav;
+use crate::views::home::{dispatch_player_action, lyrics, navigate};
+use crate::widgets::motion;
 use crate::worker::Worker;
 
+#[allow(clippy::too_many_arguments)]
 pub fn tick(
     state: &Rc<AppState>,
     worker: &Rc<Worker>,
+    media: &MediaControls,
+    hotkeys: &Hotkeys,
     rebuild: &Rc<Cell<bool>>,
     ctx: &mut SceneCtx,
     tl: &mut Timeline,
     now: Instant,
 ) {
     let mut cx = Cx::new(tl, now, rebuild);
+    // Tweens started this frame (here or from input) animate, or land at
+    // once under `--safe-mode` / low-power mode.
+    motion::set_reduced(state.reduced_effects());
     // A hot-patch landed since the last tick: rebuild so the patched
     // `Component::view` bodies run. No-op unless the `hotreload` feature is on.
     if crate::hotreload::take_patched() {
@@ -61,6 +74,14 @@ pub fn tick(
             state.router.detail_collapse.set(0.0);
         }
     }
+    // Mirror the window's logical size for the context menu's edge flip,
+    // and fold/restore the side panels across the width breakpoints.
+    // `home_root` fills the window, so its rect is the window rect.
+    if let Some(rect) = ctx.node("home_root").and_then(|id| ctx.tree.get(id)).map(|n| n.rect) {
+        let scale = ctx.scale.max(1.0);
+        state.menu.bounds.set([rect[2] / scale, rect[3] / scale]);
+        state.responsive.tick(rect[2] / scale, &state.prefs, cx.tl, cx.now);
+    }
     // Apply a cache relocation picked by the folder dialog: point the disk
     // cache at the new dir, persist it, rebuild so the storage bar refreshes.
     if let Some(dir) = state.settings.take_pending_dir() {
@@ -71,10 +92,20 @@ pub fn tick(
         cx.rebuild();
         log::info!("cache relocated to {}", dir.display());
     }
+    // Low-power mode flipped (toggle, or plugged in / unplugged): rebuild
+    // so the surfaces swap glass ↔ solid, and step the progress bar
+    // instead of tweening it every frame.
+    if state.power.tick(cx.now) {
+        cx.rebuild();
+    }
+    state.player_ui.set_stepped(state.reduced_effects(), cx.tl, cx.now);
+    // Re-tint the accent along its perceptual (OKLCH) blend.
+    state.backdrop.tick_accent();
     // Hide the base background fill once the opaque album-art backdrop fully
     // covers it — the bg behind it is dead pixels. Re-shown mid-crossfade.
     if let Some(bg) = ctx.node("home_bg") {
-        let covered = state.backdrop.covered();
+        // Reduced effects never draw the backdrop, so the fill must stay.
+        let covered = state.backdrop.covered() && !state.reduced_effects();
         ctx.tree.set_visible(bg, !covered);
     }
     // Mirror the decode thread's "video is flowing" flag into the layout
@@ -82,9 +113,52 @@ pub fn tick(
     if state.canvas.tick_active() {
         cx.rebuild();
     }
+    // Search: once the field has gone quiet, send the query and bring up
+    // the results page — typing from any page opens it; clearing the field
+    // just empties it.
+    if let Some(query) = state.search.due(cx.now) {
+        let on_page = matches!(*state.router.nav.borrow(), MainNav::Search);
+        if !query.is_empty()
+            && let Some(token) = state.auth.token()
+        {
+            worker.search(token, query.clone());
+        }
+        if on_page {
+            cx.rebuild();
+        } else if !query.is_empty() {
+            navigate(state, &mut cx, worker, MainNav::Search);
+        }
+    }
+    // Lyrics: move the highlight with playback and spring the scroller so
+    // the new line sits at reading height. The bind repaints the colours;
+    // only the scroll needs the node.
+    if matches!(*state.router.nav.borrow(), MainNav::Lyrics) {
+        let pos = state.player_ui.progress.get() * state.player_ui.duration_ms.get();
+        if let Some(i) = state.lyrics.tick(pos.max(0.0) as u64)
+            && let Some(id) = ctx.node(lyrics::SCROLL_NODE)
+            && let Some(h) = ctx.tree.get(id).map(|n| n.rect[3])
+            && let Some(head) = ctx.node(lyrics::HEAD_NODE).and_then(|n| ctx.tree.get(n))
+        {
+            let scale = ctx.scale.max(1.0);
+            let y = lyrics::scroll_target(i, h / scale, head.rect[3] / scale) * scale;
+            ctx.tree.set_scroll_target(id, [0.0, y]);
+        }
+    }
+    // Toasts: run entry/countdown/exit; a post or a removal re-lays the
+    // stack out.
+    if state.toasts.tick(cx.tl, cx.now) {
+        cx.rebuild();
+    }
+    // Turn the shared spinner while a loading state is on screen: the
+    // startup credential check, or the playlist picker before its index.
+    let loading = state.router.view.get() == crate::views::View::Splash
+        || (state.membership.overlay.is_open() && !state.membership.ready.get());
+    state.spin.tick(loading, cx.tl, cx.now);
     // Smoothly tween the Canvas dim overlay on hover transitions.
     state.canvas.tick_dim(cx.tl, cx.now);
-    // Refresh the elapsed-time label (once per second, off the live tween).
+    // Refresh the elapsed-time label (once per second, off the live tween
+    // — or off the once-a-second step in low-power mode).
+    state.player_ui.tick_stepped(cx.now);
     state.player_ui.tick_clock();
     // Commit a seek on the release edge of a progress-bar drag.
     if let Some(ms) = state.player_ui.tick_seek(cx.tl)
@@ -93,6 +167,13 @@ pub fn tick(
         let local = state.devices.playing_on_self.get();
         worker.playback(token, crate::worker::PlaybackCmd::Seek(ms), local);
     }
+    // Resume from sleep: the refresh deadline is an `Instant`, which stood
+    // still while suspended — refresh now, and resync playback + devices
+    // once the new token lands (see `TokensRefreshed` in the reducer).
+    if let Some(slept) = state.suspend.tick(cx.now) {
+        log::info!("resumed after ~{}s asleep — revalidating session", slept.as_secs());
+        state.auth.refresh_now(cx.now);
+    }
     // Proactively refresh the access token before it expires — a long
     // listening session must never start 401-ing mid-flight. Two Cell
     // reads per frame on the cold path; dispatches exactly once per due
@@ -105,6 +186,41 @@ pub fn tick(
     while let Some(resp) = worker.poll() {
         reducer::handle(state, &mut cx, worker, resp);
     }
+    // OS media keys and global shortcuts ride the same dispatcher as the
+    // player-bar buttons, tagged with their source so its conflict policy
+    // applies (stale Play/Pause). Media keys first pass the repeat guard
+    // (see `PlayerModel::admit_media_key`): a held-back second press goes
+    // out once it settles, bursts are dropped. Then mirror the (possibly
+    // just-updated) snapshot back out.
+    if let Some(action) = state.player_ui.settled_media_key(cx.now) {
+        let source = ActionSource::MediaKey;
+        dispatch_player_action(state, worker, PlayerIntent { action, source });
+    }
+    let keys = std::iter::from_fn(|| media.poll())
+        .map(|k| (k, ActionSource::MediaKey))
+        .chain(std::iter::from_fn(|| hotkeys.poll()).map(|k| (k, ActionSource::Hotkey)));
+    for (key, source) in keys {
+        let action = match key {
+            MediaKey::Play => PlayerAction::Play,
+            MediaKey::Pause => PlayerAction::Pause,
+            MediaKey::Toggle => PlayerAction::PlayPause,
+            MediaKey::Next => PlayerAction::Next,
+            MediaKey::Previous => PlayerAction::Prev,
+        };
+        if source == ActionSource::MediaKey {
+            match state.player_ui.admit_media_key(action, cx.now) {
+                KeyVerdict::Admit => {}
+                KeyVerdict::Drop => {
+                    log::debug!("media key {action:?} dropped as a repeat");
+                    continue;
+                }
+                KeyVerdict::Defer => continue,
+            }
+        }
+        dispatch_player_action(state, worker, PlayerIntent { action, source });
+    }
+    state.player_ui.tick_held_media_key(cx.tl, cx.now);
+    media.publish(state.player_ui.snapshot.borrow().as_ref());
     // A streamed page appended rows → re-materialize the open detail
     // page's lazy rows, turning any already-on-screen skeletons (fast
     // scroll outran the stream) into real tracks.
@@ -127,8 +243,10 @@ pub fn tick(
             .unwrap_or(false);
         let queue_loading = matches!(*state.router.nav.borrow(), crate::views::MainNav::Queue)
             && state.library.queue.borrow().is_none();
-        let want = queue_loading
-            || (streaming && state.router.nav.borrow().detail_scroll_node().is_some());
+        // Reduced motion: the skeletons hold still at full opacity.
+        let want = !motion::reduced()
+            && (queue_loading
+                || (streaming && state.router.nav.borrow().detail_scroll_node().is_some()));
         if want != state.library.pulse_on.get() {
             let pulse = &state.library.skeleton_pulse;
             if want {
@@ -8,6 +8,9 @@ use crate::album_art;
 use crate::api::track_id_from_uri;
 use crate::app::AppState;
 use crate::app::cx::Cx;
+use crate::i18n::tr;
+use crate::model::player::{ActionSource, PlayerIntent};
+use crate::model::toast::{ERROR_DURATION, ToastKind};
 use crate::views::View;
 use crate::worker::{Worker, WorkerResponse};
 
@@ -46,6 +49,8 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                 Some(p) => state.player_ui.sync(p, cx.tl, cx.now),
                 None => state.player_ui.stopped(cx.tl),
             }
+            let msg = tr("toast.player_unreachable");
+            state.toasts.show_for(msg, ToastKind::Error, ERROR_DURATION);
         }
         WorkerResponse::VolumeChanged { fraction } => {
             // Don't fight an in-flight drag — the release commit wins,
@@ -68,6 +73,13 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
             // Mid-session refresh: swap the live token only. Everything
             // else (home data, librespot session, Spirc device) keeps
             // running — the session authenticated once and stays up.
+            // After a resume from sleep, also pull the live player state +
+            // device list: remote playback moved on while we were out (a
+            // dropped Connect session reconnects via `SpotifySessionLost`).
+            if state.suspend.take_resync() {
+                worker.seed_player_state(auth.access_token.clone());
+                worker.fetch_devices(auth.access_token.clone());
+            }
             state.auth.set(auth);
         }
         WorkerResponse::TokensRefreshFailed => {
@@ -80,6 +92,19 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
             // else a background scan) so the heart reflects playlist
             // membership, not just Liked Songs.
             worker.load_membership(auth.access_token.clone());
+            // Background warm-up of the user's marked playlists (low
+            // priority; the worker delays + serialises it).
+            {
+                let prefs = state.prefs.data.borrow();
+                let warmup = &prefs.warmup;
+                if warmup.enabled && !warmup.playlists.is_empty() {
+                    worker.warm_playlists(
+                        auth.access_token.clone(),
+                        warmup.playlists.clone(),
+                        warmup.artworks,
+                    );
+                }
+            }
             // Cold start: the snapshot was seeded from disk, but the heart's
             // liked + membership need the API/index. Resolve them now for the
             // restored track (membership also re-resolves when the index
@@ -96,15 +121,17 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                 }
                 worker.query_membership(uri);
             }
-            let (initial_volume, quality, normalize) = {
+            let (initial_volume, quality, normalize, output_device) = {
                 let p = state.prefs.data.borrow();
-                (p.audio.volume, p.audio.quality, p.audio.normalize)
+                let a = &p.audio;
+                (a.volume, a.quality, a.normalize, a.output_device.clone())
             };
             worker.connect_spotify_session(
                 auth.access_token.clone(),
                 initial_volume,
                 quality,
                 normalize,
+                output_device,
             );
             state.auth.set(auth);
             if state.router.view.get() != View::Home {
@@ -145,6 +172,13 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
             // the rows appear.
             cx.rebuild();
         }
+        WorkerResponse::OutputDevices { names } => {
+            *state.settings.output_devices.borrow_mut() = names;
+            // Only the open settings modal shows the list.
+            if state.settings.overlay.is_open() {
+                cx.rebuild();
+            }
+        }
         WorkerResponse::ActiveDeviceChanged { device_id, is_self } => {
             // A real *other* device is active → light the Devices icon.
             state
@@ -175,8 +209,17 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                 .filter(|p| p.is_playing)
                 .map(|p| (p.track_id.clone(), p.live_progress_ms() as u32, p.context_uri.clone()));
             if let Some((track_uri, position_ms, context_uri)) = claim {
-                log::info!("active device vanished while playing — taking over on Opal (paused)");
+                // The pause is the remote device's doing (it went away),
+                // not a press of ours.
+                let source = ActionSource::Remote;
+                log::info!(
+                    "active device vanished while playing — taking over on Opal \
+                     (paused, from {source:?})"
+                );
                 worker.claim_playback_paused(context_uri, track_uri, position_ms);
+                // The transport silently changing hands would look like a
+                // glitch; say where playback went.
+                state.toasts.show(tr("toast.playback_moved"), ToastKind::Info);
             }
         }
         WorkerResponse::SavedState { track_id, saved } => {
@@ -212,6 +255,40 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                 cx.rebuild();
             }
         }
+        WorkerResponse::SearchLoaded { query, results } => {
+            // Covers for every result row, as for the queue.
+            let urls = results
+                .tracks
+                .iter()
+                .map(|t| &t.album_image_url)
+                .chain(results.artists.iter().map(|a| &a.image_url))
+                .chain(results.albums.iter().map(|a| &a.image_url))
+                .flatten();
+            for url in urls {
+                state.art.or_signal(album_art::cache_key(url));
+                state.art.dispatch_cover(worker, url.clone());
+            }
+            if state.search.apply(&query, results)
+                && matches!(*state.router.nav.borrow(), crate::views::MainNav::Search)
+            {
+                cx.rebuild();
+            }
+        }
+        WorkerResponse::SearchFailed { query, error } => {
+            log::debug!("search {query:?} failed: {error}");
+            if state.search.fail(&query)
+                && matches!(*state.router.nav.borrow(), crate::views::MainNav::Search)
+            {
+                cx.rebuild();
+            }
+        }
+        WorkerResponse::LyricsLoaded { track_id, lyrics } => {
+            if state.lyrics.apply(&track_id, lyrics)
+                && matches!(*state.router.nav.borrow(), crate::views::MainNav::Lyrics)
+            {
+                cx.rebuild();
+            }
+        }
         WorkerResponse::MembershipLoaded { playlists } => {
             log::info!("playlist-membership ready: {} playlists", playlists.len());
             state.membership.set_playlists(playlists);
@@ -230,6 +307,12 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                 cx.rebuild();
             }
         }
+        WorkerResponse::MembershipProgress { scanned, total } => {
+            let first = state.membership.set_scan(scanned, total);
+            if first && !state.membership.ready.get() && state.membership.overlay.is_open() {
+                cx.rebuild();
+            }
+        }
         WorkerResponse::TrackMembership {
             track_uri,
             playlist_ids,
@@ -280,6 +363,12 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                     .open_remove_track(false, &playlist_id, &track_uri);
             }
             state.library.invalidate_cached(&playlist_id);
+            let msg = tr(if was_add {
+                "toast.playlist_add_failed"
+            } else {
+                "toast.playlist_remove_failed"
+            });
+            state.toasts.show_for(msg, ToastKind::Error, ERROR_DURATION);
             cx.rebuild();
         }
         WorkerResponse::SpotifySessionFailed { error } => {
@@ -291,11 +380,18 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
             // without an app restart. The worker already backed off.
             if let Some(token) = state.auth.token() {
                 log::warn!("librespot session lost — reconnecting Connect device");
-                let (initial_volume, quality, normalize) = {
+                let (initial_volume, quality, normalize, output_device) = {
                     let p = state.prefs.data.borrow();
-                    (p.audio.volume, p.audio.quality, p.audio.normalize)
+                    let a = &p.audio;
+                    (a.volume, a.quality, a.normalize, a.output_device.clone())
                 };
-                worker.connect_spotify_session(token, initial_volume, quality, normalize);
+                worker.connect_spotify_session(
+                    token,
+                    initial_volume,
+                    quality,
+                    normalize,
+                    output_device,
+                );
             }
         }
         WorkerResponse::PlayerState { mut player } => {
@@ -396,6 +492,11 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                     {
                         worker.fetch_queue(token);
                     }
+                    // The lyrics page follows the playing track.
+                    if matches!(*state.router.nav.borrow(), crate::views::MainNav::Lyrics) {
+                        state.lyrics.ensure(worker, &p);
+                        cx.rebuild();
+                    }
                 }
                 // Canvas video: fetch on a real track change (not a
                 // progress tick). Gate on the cached canvas not already
@@ -407,7 +508,7 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
                         "canvas gate: track={id} have={have} show={}",
                         state.canvas.show.get()
                     );
-                    if !have && state.canvas.show.get() {
+                    if !have && state.canvas.show.get() && state.canvas_video() {
                         state.canvas.clear_path();
                         // Stop the previous track's video now so it doesn't
                         // linger over the new track's art until the new
@@ -429,7 +530,14 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
             // `prefs.last_player`, so we just mark stopped + freeze the bar
             // rather than clobbering that restored state to a dash.
             match player.as_ref() {
-                Some(p) => state.player_ui.sync(p, cx.tl, cx.now),
+                Some(p) => {
+                    // Another device's play/pause or shuffle/repeat shows
+                    // up only as state; attribute it like a local intent.
+                    for PlayerIntent { action, source } in state.player_ui.remote_intents(p) {
+                        log::debug!("player action {action:?} from {source:?}");
+                    }
+                    state.player_ui.sync(p, cx.tl, cx.now);
+                }
                 None => state.player_ui.stopped(cx.tl),
             }
             *state.player_ui.snapshot.borrow_mut() = player;
@@ -535,7 +643,7 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
             // Only decode if still wanted (canvas enabled). A late arrival
             // for a track the user already skipped past is harmless — the
             // next track change stops/replaces this session.
-            if state.canvas.show.get() {
+            if state.canvas.show.get() && state.canvas_video() {
                 state.canvas.start_decode(track_id, path);
             }
         }
@@ -614,6 +722,10 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
         WorkerResponse::PlaylistFailed { id, error } => {
             state.library.clear_inflight(&id);
             log::warn!("playlist {id} load failed: {error}");
+            if state.router.nav_is_open(&id) {
+                let msg = tr("toast.page_failed");
+                state.toasts.show_for(msg, ToastKind::Error, ERROR_DURATION);
+            }
         }
         WorkerResponse::ArtistOpened {
             id,
@@ -655,6 +767,10 @@ pub fn handle(state: &Rc<AppState>, cx: &mut Cx, worker: &Rc<Worker>, resp: Work
         WorkerResponse::ArtistFailed { id, error } => {
             state.library.clear_inflight(&id);
             log::warn!("artist {id} load failed: {error}");
+            if state.router.nav_is_artist(&id) {
+                let msg = tr("toast.artist_failed");
+                state.toasts.show_for(msg, ToastKind::Error, ERROR_DURATION);
+            }
         }
         WorkerResponse::TrackDetails { details } => {
             let track_id = details.track_id.clone();
@@ -5,10 +5,12 @@
 //! per-domain logic lives on the models themselves.
 
 use crate::model::{
-    ArtModel, AuthModel, BackdropModel, CanvasModel, DevicesModel, LibraryModel, MembershipModel,
-    MenuModel, PlayerModel, PrefsModel, RouterModel, SettingsModel,
+    ArtModel, AuthModel, BackdropModel, CanvasModel, DevicesModel, LibraryModel, LyricsModel,
+    MembershipModel, MenuModel, PlayerModel, PowerModel, PrefsModel, ResponsiveModel, RouterModel,
+    SearchModel, SettingsModel, SuspendModel, ToastModel,
 };
 use crate::prefs::UserPreferences;
+use crate::widgets::spinner::Spin;
 
 pub struct AppState {
     /// View-routing slice: top-level view + centre-pane nav + entrance
@@ -41,10 +43,33 @@ pub struct AppState {
     pub membership: MembershipModel,
     /// Persisted-preferences slice + panel widths + debounced save.
     pub prefs: PrefsModel,
+    /// Window-width breakpoints that fold the side panels.
+    pub responsive: ResponsiveModel,
+    /// Top-bar search: field debounce + latest results.
+    pub search: SearchModel,
+    /// Lyrics page: current track's lyrics + highlighted line.
+    pub lyrics: LyricsModel,
+    /// Suspend/resume detection — forces a token refresh + playback resync
+    /// after the machine wakes.
+    pub suspend: SuspendModel,
+    /// Transient corner notifications (see [`ToastModel::show`]).
+    pub toasts: ToastModel,
+    /// The one clock every loading spinner turns on (see
+    /// [`crate::widgets::spinner`]); runs only while one is showing.
+    pub spin: Spin,
+    /// Low-power rendering: the "reduce effects" toggles + battery poll.
+    pub power: PowerModel,
+    /// `--safe-mode` launch: skip the GPU-heavy extras (ambient album-art
+    /// backdrop + its frosted glass, Canvas video, and — in the worker —
+    /// every cover image) and land the app's tweens at once
+    /// ([`crate::widgets::motion`]) so a rendering problem on a given
+    /// GPU/driver can be isolated. Session-only — never persisted, and the
+    /// user's own toggles are left untouched.
+    pub safe_mode: bool,
 }
 
 impl AppState {
-    pub fn from_prefs(prefs: UserPreferences) -> Self {
+    pub fn from_prefs(prefs: UserPreferences, safe_mode: bool) -> Self {
         // Seed the player chrome from the persisted snapshot so cold start
         // renders the last-played track immediately instead of a dash. The
         // first live cluster push overwrites these; if Spotify has nothing
@@ -67,6 +92,7 @@ impl AppState {
         // cold start, leaving the heart blank until the track actually
         // changes. The first live cluster push overwrites it.
         let restored = prefs.last_player.clone();
+        let power = PowerModel::new(prefs.effects.reduce, prefs.effects.reduce_on_battery);
         let state = Self {
             router: RouterModel::new(),
             auth: AuthModel::new(),
@@ -82,11 +108,19 @@ impl AppState {
                 duration_ms,
                 prefs.audio.volume,
             ),
-            settings: SettingsModel::new(prefs.audio.normalize),
+            settings: SettingsModel::new(prefs.audio.normalize, prefs.warmup.enabled),
             devices: DevicesModel::new(),
             menu: MenuModel::new(),
             membership: MembershipModel::new(),
             prefs: PrefsModel::new(prefs),
+            responsive: ResponsiveModel::new(),
+            search: SearchModel::new(),
+            lyrics: LyricsModel::new(prefs.lyrics_lookup),
+            suspend: SuspendModel::new(),
+            toasts: ToastModel::new(),
+            spin: Spin::new(),
+            power,
+            safe_mode,
         };
         if let Some(p) = restored {
             *state.player_ui.snapshot.borrow_mut() = Some(crate::api::CurrentlyPlaying {
@@ -105,4 +139,19 @@ impl AppState {
         }
         state
     }
+
+    /// Whether Canvas video plays at all: compiled in (`canvas-video`
+    /// feature) and not disabled by `--safe-mode`. The `show_canvas` pref
+    /// is checked separately — it's the user's choice, kept as-is.
+    pub fn canvas_video(&self) -> bool {
+        cfg!(feature = "canvas-video") && !self.safe_mode
+    }
+
+    /// Whether surfaces render solid instead of glass/backdrop (applied
+    /// per build through `widgets::surface`) and tweens land at once
+    /// (per frame through `widgets::motion`): always under `--safe-mode`,
+    /// otherwise per the low-power toggles.
+    pub fn reduced_effects(&self) -> bool {
+        self.safe_mode || self.power.active()
+    }
 }
@@ -0,0 +1,231 @@
+//! Display formatting for durations, counts and sizes — the one place
+//! every view formats numbers through, so labels read consistently across
+//! the player bar, detail headers, artist page, history and settings.
+//!
+//! Separators, unit names and plural phrases come from the active locale
+//! bundle (`format.*` keys, see [`crate::i18n`]), so a translation changes
+//! `1,234` to `1.234` or `3 hr 24 min` to `3 Std. 24 Min.` without touching
+//! a view. Templates carry the number as `{n}`; plurals pick between a
+//! `.one` and an `.other` form, which covers the bundled English and the
+//! common European locales (languages with more plural classes get the
+//! `.other` form for every count but one).
+
+use std::time::Duration;
+
+use crate::i18n::{tr, tr_n};
+
+/// Plural phrase under `key` for `n`, showing `n` as `shown`:
+/// `{key}.one` for 1, else `{key}.other`.
+fn plural(key: &str, n: u64, shown: String) -> String {
+    let form = if n == 1 { "one" } else { "other" };
+    tr_n(&format!("{key}.{form}"), shown)
+}
+
+/// `x` to `places` decimals with the locale's decimal separator.
+fn decimal(x: f64, places: usize) -> String {
+    format!("{x:.places$}").replace('.', &tr("format.decimal_sep"))
+}
+
+/// `ms` → clock time: `M:SS`, or `H:MM:SS` from an hour up (long
+/// podcast episodes / mixes).
+pub fn clock(ms: u64) -> String {
+    let secs = ms / 1000;
+    let (h, m, s) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if h > 0 {
+        format!("{h}:{m:02}:{s:02}")
+    } else {
+        format!("{m}:{s:02}")
+    }
+}
+
+/// `ms` → spelled-out total length for headers: `3 hr 24 min`, `42 min`,
+/// or `55 sec` under a minute. Seconds are dropped once there are minutes.
+pub fn total_length(ms: u64) -> String {
+    let secs = ms / 1000;
+    let (h, m) = (secs / 3600, (secs / 60) % 60);
+    match (h, m) {
+        (0, 0) => tr_n("format.sec", secs),
+        (0, m) => tr_n("format.min", m),
+        (h, 0) => tr_n("format.hr", h),
+        (h, m) => format!("{} {}", tr_n("format.hr", h), tr_n("format.min", m)),
+    }
+}
+
+/// How long ago something happened, for history rows: `just now` under a
+/// minute, then the largest whole unit — `5 minutes ago`, `2 days ago`,
+/// `3 months ago`. Months are 30 days and years 365; close enough for a
+/// label that only ever rounds down.
+pub fn relative(ago: Duration) -> String {
+    const MIN: u64 = 60;
+    const HOUR: u64 = 60 * MIN;
+    const DAY: u64 = 24 * HOUR;
+    const WEEK: u64 = 7 * DAY;
+    const MONTH: u64 = 30 * DAY;
+    const YEAR: u64 = 365 * DAY;
+    let s = ago.as_secs();
+    let (n, unit) = match s {
+        _ if s < MIN => return tr("format.ago.now"),
+        _ if s < HOUR => (s / MIN, "minute"),
+        _ if s < DAY => (s / HOUR, "hour"),
+        _ if s < WEEK => (s / DAY, "day"),
+        _ if s < MONTH => (s / WEEK, "week"),
+        _ if s < YEAR => (s / MONTH, "month"),
+        _ => (s / YEAR, "year"),
+    };
+    plural(&format!("format.ago.{unit}"), n, grouped(n))
+}
+
+/// Integer with the locale's thousands separator: `1234567` → `1,234,567`.
+pub fn grouped(n: u64) -> String {
+    let sep = tr("format.group_sep");
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 * sep.len());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push_str(&sep);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Compact magnitude for big counts: `1.2M`, `12.3K`, `742`. The unit is
+/// picked from the *rounded* value, so `999,950` reads `1.0M` rather than
+/// rolling over to `1000.0K`.
+pub fn compact(n: u64) -> String {
+    if n >= 999_950_000 {
+        tr_n("format.billions", decimal(n as f64 / 1e9, 1))
+    } else if n >= 999_950 {
+        tr_n("format.millions", decimal(n as f64 / 1e6, 1))
+    } else if n >= 1_000 {
+        tr_n("format.thousands", decimal(n as f64 / 1e3, 1))
+    } else {
+        n.to_string()
+    }
+}
+
+/// `n` + counted noun, in the locale's plural form with grouped digits:
+/// `1 song`, `1,204 songs`. `noun` names a `format.<noun>` plural pair in
+/// the bundle.
+pub fn count(n: u64, noun: &str) -> String {
+    plural(&format!("format.{noun}"), n, grouped(n))
+}
+
+/// [`count`] with the number in [`compact`] form, for headline figures:
+/// `1 follower`, `742 followers`, `1.2M followers`.
+pub fn count_compact(n: u64, noun: &str) -> String {
+    plural(&format!("format.{noun}"), n, compact(n))
+}
+
+/// Byte size in binary units: `512 B`, `14 KB`, `3.2 MB`, `1.25 GB`. As
+/// with [`compact`], the unit is picked from the *rounded* value, so
+/// 1023.6 KB reads `1.0 MB` rather than `1024 KB`.
+pub fn bytes(b: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+    let f = b as f64;
+    // Shown in `unit` to `places` decimals, would it read 1024 or more?
+    let rolls_over = |unit: f64, places: i32| {
+        let scale = 10f64.powi(places);
+        (f / unit * scale).round() / scale >= 1024.0
+    };
+    if f >= GB || rolls_over(MB, 1) {
+        tr_n("format.gb", decimal(f / GB, 2))
+    } else if f >= MB || rolls_over(KB, 0) {
+        tr_n("format.mb", decimal(f / MB, 1))
+    } else if f >= KB {
+        tr_n("format.kb", decimal(f / KB, 0))
+    } else {
+        tr_n("format.b", b)
+    }
+}
+
+/// A 0..=1 fraction as a whole percentage: `0.456` → `46%`.
+pub fn percent(frac: f32) -> String {
+    let pct = (frac.clamp(0.0, 1.0) * 100.0).round() as u32;
+    tr_n("format.percent", pct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_switches_to_hours_past_sixty_minutes() {
+        assert_eq!(clock(0), "0:00");
+        assert_eq!(clock(225_000), "3:45");
+        assert_eq!(clock(3_599_999), "59:59");
+        assert_eq!(clock(3_600_000 + 62_000), "1:01:02");
+    }
+
+    #[test]
+    fn total_length_drops_seconds_once_minutes_show() {
+        assert_eq!(total_length(55_000), "55 sec");
+        assert_eq!(total_length(42 * 60_000 + 30_000), "42 min");
+        assert_eq!(total_length(2 * 3_600_000), "2 hr");
+        assert_eq!(total_length(3 * 3_600_000 + 24 * 60_000), "3 hr 24 min");
+    }
+
+    #[test]
+    fn relative_rounds_down_to_the_largest_unit() {
+        let s = Duration::from_secs;
+        assert_eq!(relative(s(30)), "just now");
+        assert_eq!(relative(s(60)), "1 minute ago");
+        assert_eq!(relative(s(59 * 60)), "59 minutes ago");
+        assert_eq!(relative(s(2 * 3600 + 59 * 60)), "2 hours ago");
+        assert_eq!(relative(s(2 * 86_400)), "2 days ago");
+        assert_eq!(relative(s(13 * 86_400)), "1 week ago");
+        assert_eq!(relative(s(95 * 86_400)), "3 months ago");
+        assert_eq!(relative(s(800 * 86_400)), "2 years ago");
+    }
+
+    #[test]
+    fn grouped_and_count_insert_separators() {
+        assert_eq!(grouped(0), "0");
+        assert_eq!(grouped(999), "999");
+        assert_eq!(grouped(1_000), "1,000");
+        assert_eq!(grouped(1_234_567), "1,234,567");
+        assert_eq!(count(1, "song"), "1 song");
+        assert_eq!(count(0, "song"), "0 songs");
+        assert_eq!(count(1_204, "song"), "1,204 songs");
+    }
+
+    #[test]
+    fn compact_and_percent_round_sensibly() {
+        assert_eq!(compact(742), "742");
+        assert_eq!(compact(999), "999");
+        assert_eq!(compact(1_000), "1.0K");
+        assert_eq!(compact(12_345), "12.3K");
+        assert_eq!(compact(999_949), "999.9K");
+        assert_eq!(compact(999_950), "1.0M");
+        assert_eq!(compact(1_240_000), "1.2M");
+        assert_eq!(compact(999_949_999), "999.9M");
+        assert_eq!(compact(999_950_000), "1.0B");
+        assert_eq!(count_compact(1, "follower"), "1 follower");
+        assert_eq!(count_compact(1_240_000, "follower"), "1.2M followers");
+        assert_eq!(percent(0.456), "46%");
+        assert_eq!(percent(1.7), "100%");
+    }
+
+    #[test]
+    fn bytes_move_up_a_unit_when_rounding_reaches_1024() {
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(14 * 1024), "14 KB");
+        assert_eq!(bytes(1023 * 1024 + 500), "1023 KB");
+        assert_eq!(bytes(1023 * 1024 + 600), "1.0 MB");
+        assert_eq!(bytes(1024 * 1024 * 1024 - 1), "1.00 GB");
+    }
+
+    #[test]
+    fn separators_and_units_follow_the_locale() {
+        crate::i18n::use_bundle(
+            "format.group_sep = \".\"\nformat.decimal_sep = ,\n\
+             format.mb = {n} Mo\nformat.song.other = {n} Titel",
+        );
+        assert_eq!(grouped(1_234_567), "1.234.567");
+        assert_eq!(count(1_204, "song"), "1.204 Titel");
+        assert_eq!(compact(12_345), "12,3K");
+        assert_eq!(bytes(3 * 1024 * 1024 + 200 * 1024), "3,2 Mo");
+    }
+}
@@ -0,0 +1,158 @@
+//! System-wide transport shortcuts — play/pause, next and previous from
+//! any app, window focused or not — via `global-hotkey`. Feature-gated
+//! behind `global-hotkeys` (on by default); with it off every entry point
+//! is a no-op shim, the same shape as [`crate::media_controls`].
+//!
+//! The bindings live in prefs (`hotkeys`, see [`HotkeyPrefs`]) as
+//! accelerator strings like `"ctrl+alt+Space"`; one that fails to parse or
+//! is already taken by another app is skipped with a warning, the rest
+//! still register. Nothing is bound by default: the obvious chords
+//! (ctrl+alt+arrows, ctrl+alt+Space) are workspace shortcuts on stock
+//! GNOME/KDE, so the user picks their own. Presses and releases arrive
+//! on the platform's thread and are queued + wake the loop. The frame
+//! tick drains presses as [`MediaKey`]s through the same dispatcher as
+//! the OS media keys. A key's auto-repeat while it's held down is
+//! swallowed here, since this is the one source that reports releases.
+//!
+//! Linux note: the grabs go through X11, so under Wayland they only see
+//! keys while an XWayland window has focus. Without a display connection
+//! at all, registration fails and is logged (fail-soft: the app runs
+//! exactly as without the feature).
+
+use std::sync::Arc;
+
+use opal_gfx::WakeHandle;
+
+use crate::media_controls::MediaKey;
+use crate::prefs::HotkeyPrefs;
+
+pub struct Hotkeys {
+    #[cfg(feature = "global-hotkeys")]
+    inner: Option<imp::Registered>,
+}
+
+impl Hotkeys {
+    /// Register the configured shortcuts (nothing when `prefs.enabled` is
+    /// off or no key is bound). Never fails — an unavailable backend just
+    /// logs.
+    pub fn new(prefs: &HotkeyPrefs, wake: Arc<WakeHandle>) -> Self {
+        #[cfg(feature = "global-hotkeys")]
+        {
+            let bound = [&prefs.play_pause, &prefs.next, &prefs.previous]
+                .iter()
+                .any(|a| !a.trim().is_empty());
+            if !prefs.enabled || !bound {
+                return Self { inner: None };
+            }
+            let inner = match imp::Registered::new(prefs, wake) {
+                Ok(r) => Some(r),
+                Err(e) => {
+                    log::warn!("global hotkeys unavailable: {e}");
+                    None
+                }
+            };
+            Self { inner }
+        }
+        #[cfg(not(feature = "global-hotkeys"))]
+        {
+            let _ = (prefs, wake);
+            Self {}
+        }
+    }
+
+    /// UI-thread drain: the next pressed shortcut, if any.
+    pub fn poll(&self) -> Option<MediaKey> {
+        #[cfg(feature = "global-hotkeys")]
+        {
+            self.inner.as_ref().and_then(|r| r.poll())
+        }
+        #[cfg(not(feature = "global-hotkeys"))]
+        {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "global-hotkeys")]
+mod imp {
+    use std::cell::RefCell;
+    use std::sync::Arc;
+    use std::sync::mpsc::{Receiver, channel};
+
+    use global_hotkey::hotkey::HotKey;
+    use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+    use opal_gfx::WakeHandle;
+
+    use crate::media_controls::MediaKey;
+    use crate::prefs::HotkeyPrefs;
+
+    pub struct Registered {
+        /// Owns the OS registrations — dropping it releases them.
+        _manager: GlobalHotKeyManager,
+        /// Registered hotkey id → the key it stands for.
+        bindings: Vec<(u32, MediaKey)>,
+        /// `(id, pressed)` edges, fed by the event handler.
+        rx: Receiver<(u32, bool)>,
+        /// Ids pressed and not yet released — repeats of these are dropped.
+        held: RefCell<Vec<u32>>,
+    }
+
+    impl Registered {
+        pub fn new(prefs: &HotkeyPrefs, wake: Arc<WakeHandle>) -> Result<Self, String> {
+            let manager = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
+            let mut bindings = Vec::new();
+            for (accel, key) in [
+                (&prefs.play_pause, MediaKey::Toggle),
+                (&prefs.next, MediaKey::Next),
+                (&prefs.previous, MediaKey::Previous),
+            ] {
+                if accel.trim().is_empty() {
+                    continue;
+                }
+                let hotkey: HotKey = match accel.parse() {
+                    Ok(h) => h,
+                    Err(e) => {
+                        log::warn!("hotkey {accel:?} ignored: {e}");
+                        continue;
+                    }
+                };
+                match manager.register(hotkey) {
+                    Ok(()) => bindings.push((hotkey.id(), key)),
+                    Err(e) => log::warn!("hotkey {accel:?} not registered: {e}"),
+                }
+            }
+            // A custom handler replaces the crate's own channel, so both
+            // edges are forwarded to ours along with a wake.
+            let (tx, rx) = channel();
+            GlobalHotKeyEvent::set_event_handler(Some(move |e: GlobalHotKeyEvent| {
+                let _ = tx.send((e.id, e.state == HotKeyState::Pressed));
+                wake.wake();
+            }));
+            log::info!("global hotkeys: {} registered", bindings.len());
+            Ok(Self {
+                _manager: manager,
+                bindings,
+                rx,
+                held: RefCell::new(Vec::new()),
+            })
+        }
+
+        pub fn poll(&self) -> Option<MediaKey> {
+            while let Ok((id, pressed)) = self.rx.try_recv() {
+                let mut held = self.held.borrow_mut();
+                if !pressed {
+                    held.retain(|h| *h != id);
+                    continue;
+                }
+                if held.contains(&id) {
+                    continue;
+                }
+                held.push(id);
+                if let Some(&(_, key)) = self.bindings.iter().find(|(bid, _)| *bid == id) {
+                    return Some(key);
+                }
+            }
+            None
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! UI string lookup — [`tr`] resolves a key like `"login.button"` in the
+//! active locale's bundle.
+//!
+//! A bundle is a plain `key = value` text file. English ships embedded
+//! (`assets/i18n/en.txt`) and is the fallback for every key; other
+//! locales are read from `<config>/opal/locales/<code>.txt`, so a
+//! translation can be dropped in without a rebuild. A key missing from
+//! both resolves to itself, which makes an untranslated string obvious on
+//! screen rather than blank.
+//!
+//! The active locale is chosen at startup from the `locale` pref (see
+//! [`init`]) and switched from the settings "Language" picker, which
+//! offers [`locales`]. Views resolve their strings while building, so a
+//! switch is [`set_locale`] followed by a scene rebuild. Every screen is
+//! keyed except the debug gallery; the app name stays a literal.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+const EN: &str = include_str!("../assets/i18n/en.txt");
+
+thread_local! {
+    static EN_BUNDLE: HashMap<String, String> = parse(EN);
+    /// The loaded translation; `None` = English.
+    static ACTIVE: RefCell<Option<HashMap<String, String>>> = const { RefCell::new(None) };
+}
+
+/// Parse a bundle: `key = value` lines; blank lines and `#` comments
+/// skipped, whitespace around key and value trimmed. A value wrapped in
+/// double quotes keeps its inner whitespace (`format.group_sep = " "`).
+fn parse(text: &str) -> HashMap<String, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| l.split_once('='))
+        .map(|(k, v)| {
+            let v = v.trim();
+            let v = v
+                .strip_prefix('"')
+                .and_then(|q| q.strip_suffix('"'))
+                .unwrap_or(v);
+            (k.trim().to_owned(), v.to_owned())
+        })
+        .collect()
+}
+
+/// `<config_dir>/opal/locales`.
+fn locales_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("opal").join("locales"))
+}
+
+/// `<config_dir>/opal/locales/<code>.txt`.
+fn bundle_path(code: &str) -> Option<PathBuf> {
+    locales_dir().map(|d| d.join(format!("{code}.txt")))
+}
+
+/// Every pickable locale as `(code, name)`: English first, then each
+/// bundle in the locales folder by code. `name` is the bundle's own
+/// `locale.name` ("Deutsch"), else its code. Reads the folder — call on
+/// settings open, not per build.
+pub fn locales() -> Vec<(String, String)> {
+    let english = EN_BUNDLE.with(|en| en.get("locale.name").cloned()).unwrap_or_default();
+    let mut found: Vec<(String, String)> = locales_dir()
+        .and_then(|d| std::fs::read_dir(d).ok())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let path = e.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            let code = path.file_stem()?.to_str()?.to_owned();
+            if code.eq_ignore_ascii_case("en") {
+                return None;
+            }
+            let text = std::fs::read_to_string(&path).ok()?;
+            let name = parse(&text).remove("locale.name").unwrap_or_else(|| code.clone());
+            Some((code, name))
+        })
+        .collect();
+    found.sort();
+    std::iter::once(("en".to_owned(), english)).chain(found).collect()
+}
+
+/// Apply the `locale` pref at startup. `None` (or a locale with no
+/// bundle) stays on English.
+pub fn init(locale: Option<&str>) {
+    if let Some(code) = locale
+        && !set_locale(code)
+    {
+        log::warn!("locale {code:?}: no bundle found, using English");
+    }
+}
+
+/// Switch the active locale. `false` (and no change) when `code` isn't
+/// English and has no readable bundle. The caller rebuilds the scene so
+/// already-built text picks the new strings up.
+pub fn set_locale(code: &str) -> bool {
+    let code = code.trim();
+    if code.is_empty() || code.eq_ignore_ascii_case("en") {
+        ACTIVE.with_borrow_mut(|a| *a = None);
+        return true;
+    }
+    let Some(text) = bundle_path(code).and_then(|p| std::fs::read_to_string(p).ok()) else {
+        return false;
+    };
+    let strings = parse(&text);
+    log::info!("locale {code}: {} strings", strings.len());
+    ACTIVE.with_borrow_mut(|a| *a = Some(strings));
+    true
+}
+
+/// The string for `key`: the active locale's, else English, else `key`.
+pub fn tr(key: &str) -> String {
+    ACTIVE
+        .with_borrow(|a| a.as_ref().and_then(|b| b.get(key).cloned()))
+        .or_else(|| EN_BUNDLE.with(|en| en.get(key).cloned()))
+        .unwrap_or_else(|| key.to_owned())
+}
+
+/// [`tr`] with the template's `{n}` filled in: `"Cleared {n}"`.
+pub fn tr_n(key: &str, n: impl Display) -> String {
+    tr(key).replace("{n}", &n.to_string())
+}
+
+/// Make `text` the active bundle, for tests of locale-driven output.
+#[cfg(test)]
+pub fn use_bundle(text: &str) {
+    ACTIVE.with_borrow_mut(|a| *a = Some(parse(text)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_parses_and_falls_back_to_english() {
+        let b = parse("# comment\n\n a.b = Hallo = Welt \nbroken line\nsep = \" \"\n");
+        assert_eq!(b.get("a.b").map(String::as_str), Some("Hallo = Welt"));
+        assert_eq!(b.get("sep").map(String::as_str), Some(" "));
+        assert_eq!(b.len(), 2);
+
+        assert_eq!(tr("login.button"), "Log in with Spotify");
+        assert_eq!(tr("no.such.key"), "no.such.key");
+        ACTIVE.with_borrow_mut(|a| *a = Some(parse("login.button = Mit Spotify anmelden")));
+        assert_eq!(tr("login.button"), "Mit Spotify anmelden");
+        assert_eq!(tr("login.back"), "Back", "missing key falls back");
+    }
+}
@@ -0,0 +1,182 @@
+//! Lyrics for the now-playing track, from [LRCLIB](https://lrclib.net) —
+//! a free, keyless lyrics database serving LRC-timestamped ("synced")
+//! lyrics where available and plain text otherwise.
+//!
+//! Spotify's own lyrics endpoint needs a web-player token, not the OAuth
+//! token Opal holds, so it isn't an option. LRCLIB is matched on title +
+//! primary artist, then narrowed by duration (±[`DURATION_SLACK_MS`]) so a
+//! live or extended cut doesn't pick up the album version's timings.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::AuthError;
+
+const SEARCH_URL: &str = "https://lrclib.net/api/search";
+
+/// LRCLIB asks clients to identify themselves.
+const USER_AGENT: &str = concat!("Opal/", env!("CARGO_PKG_VERSION"));
+
+/// Duration mismatch still accepted as the same recording.
+const DURATION_SLACK_MS: u64 = 3_000;
+
+/// One lyric line. `at_ms` is the line's start for synced lyrics, `0` for
+/// every line of plain lyrics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricLine {
+    pub at_ms: u64,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lyrics {
+    pub lines: Vec<LyricLine>,
+    /// Lines carry real timestamps (highlight + click-to-seek apply).
+    pub synced: bool,
+}
+
+/// Parse an LRC document. Metadata tags (`[ar:…]`, `[offset:…]`) and
+/// untimed lines are skipped; a line with several stamps
+/// (`[00:12.00][01:40.00]chorus`) is emitted once per stamp; the result is
+/// sorted by time.
+pub fn parse_lrc(src: &str) -> Vec<LyricLine> {
+    let mut out = Vec::new();
+    for raw in src.lines() {
+        let mut rest = raw.trim();
+        let mut stamps = Vec::new();
+        while let Some(tail) = rest.strip_prefix('[') {
+            let Some((tag, after)) = tail.split_once(']') else {
+                break;
+            };
+            match parse_stamp(tag) {
+                Some(ms) => stamps.push(ms),
+                None => break,
+            }
+            rest = after;
+        }
+        let text = rest.trim();
+        out.extend(stamps.into_iter().map(|at_ms| LyricLine {
+            at_ms,
+            text: text.to_string(),
+        }));
+    }
+    out.sort_by_key(|l| l.at_ms);
+    out
+}
+
+/// `mm:ss`, `mm:ss.xx` or `mm:ss.xxx` → milliseconds.
+fn parse_stamp(tag: &str) -> Option<u64> {
+    let (min, sec) = tag.split_once(':')?;
+    let min: u64 = min.parse().ok()?;
+    let (whole, frac) = sec.split_once('.').unwrap_or((sec, ""));
+    let whole: u64 = whole.parse().ok()?;
+    let frac_ms = match frac.len() {
+        0 => 0,
+        1..=3 => frac.parse::<u64>().ok()? * 10u64.pow(3 - frac.len() as u32),
+        _ => return None,
+    };
+    Some(min * 60_000 + whole * 1_000 + frac_ms)
+}
+
+/// Index of the line playing at `pos_ms`: the last one started at or
+/// before it. `None` before the first line.
+pub fn active_line(lines: &[LyricLine], pos_ms: u64) -> Option<usize> {
+    lines.partition_point(|l| l.at_ms <= pos_ms).checked_sub(1)
+}
+
+/// Look a recording up on LRCLIB. `Ok(None)` when there's no match or the
+/// match has no lyrics (instrumentals are flagged as such upstream).
+pub async fn fetch(
+    title: &str,
+    artist: &str,
+    duration_ms: u64,
+) -> Result<Option<Lyrics>, AuthError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Hit {
+        #[serde(default)]
+        duration: f64,
+        #[serde(default)]
+        instrumental: bool,
+        #[serde(default)]
+        plain_lyrics: Option<String>,
+        #[serde(default)]
+        synced_lyrics: Option<String>,
+    }
+    let url =
+        url::Url::parse_with_params(SEARCH_URL, [("track_name", title), ("artist_name", artist)])
+            .map_err(|e| AuthError::Parse(e.to_string()))?;
+    let res = reqwest::Client::new()
+        .get(url)
+        .header(reqwest::header::USER_AGENT, USER_AGENT)
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await?;
+    if !res.status().is_success() {
+        let status = res.status().as_u16();
+        let body = res.text().await.unwrap_or_default();
+        return Err(AuthError::Api(body, Some(status)));
+    }
+    let hits: Vec<Hit> = serde_json::from_slice(&res.bytes().await?)?;
+    let close =
+        |h: &&Hit| ((h.duration * 1000.0) as u64).abs_diff(duration_ms) <= DURATION_SLACK_MS;
+    // Prefer a synced match; fall back to any plain one.
+    let synced = hits
+        .iter()
+        .filter(close)
+        .find_map(|h| h.synced_lyrics.as_deref().map(parse_lrc))
+        .filter(|lines| !lines.is_empty());
+    if let Some(lines) = synced {
+        return Ok(Some(Lyrics {
+            lines,
+            synced: true,
+        }));
+    }
+    let plain = hits
+        .iter()
+        .filter(close)
+        .filter(|h| !h.instrumental)
+        .find_map(|h| h.plain_lyrics.as_deref());
+    Ok(plain.map(|text| Lyrics {
+        lines: text
+            .lines()
+            .map(|l| LyricLine {
+                at_ms: 0,
+                text: l.trim().to_string(),
+            })
+            .collect(),
+        synced: false,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_stamps_tags_and_repeats() {
+        let lrc = "[ar:Someone]\n[00:01.5]one\n[00:12.00][01:02.345]chorus\n\nno stamp\n[00:05]";
+        let lines = parse_lrc(lrc);
+        let got: Vec<_> = lines.iter().map(|l| (l.at_ms, l.text.as_str())).collect();
+        assert_eq!(
+            got,
+            [
+                (1_500, "one"),
+                (5_000, ""),
+                (12_000, "chorus"),
+                (62_345, "chorus")
+            ]
+        );
+    }
+
+    #[test]
+    fn active_line_is_the_last_started() {
+        let lines = parse_lrc("[00:01.00]a\n[00:03.00]b\n[00:03.00]c\n[00:09.00]d");
+        assert_eq!(active_line(&lines, 0), None);
+        assert_eq!(active_line(&lines, 1_000), Some(0));
+        assert_eq!(active_line(&lines, 3_500), Some(2));
+        assert_eq!(active_line(&lines, 60_000), Some(3));
+        assert_eq!(active_line(&[], 5_000), None);
+    }
+}
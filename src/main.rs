@@ -15,12 +15,20 @@ mod debug_config;
 mod disk_cache;
 mod errors;
 mod extracted_color;
+mod format;
+mod hotkeys;
 mod hotreload;
+mod i18n;
 mod local_player;
+mod lyrics;
+mod media_controls;
 mod model;
+mod power;
 mod prefs;
+mod sort;
 mod spirc_bootstrap;
 mod spotify_session;
+#[cfg(feature = "canvas-video")]
 mod video;
 mod views;
 mod widgets;
@@ -113,7 +121,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         None => (win_w, win_h),
     };
 
-    let state = Rc::new(AppState::from_prefs(prefs));
+    // `--safe-mode` (or `OPAL_SAFE_MODE`): launch without the backdrop
+    // glass, Canvas video, cover art and animations, for isolating
+    // GPU/driver rendering issues.
+    let safe_mode = std::env::args().any(|a| a == "--safe-mode")
+        || std::env::var_os("OPAL_SAFE_MODE").is_some();
+    if safe_mode {
+        log::warn!("safe mode: backdrop, glass, Canvas video, cover art and animations disabled");
+    }
+    i18n::init(prefs.locale.as_deref());
+    let state = Rc::new(AppState::from_prefs(prefs, safe_mode));
+    // Debug builds: `--stress N,M,P` mounts the synthetic layout stress
+    // scene instead of the app (see `views::stress`).
+    #[cfg(debug_assertions)]
+    let stress = views::stress::StressConfig::from_args();
+    // Debug builds: `--gallery [tab]` mounts the widget gallery instead
+    // (see `views::gallery`).
+    #[cfg(debug_assertions)]
+    let gallery_tab = views::gallery::Tab::from_args();
     let force_home = std::env::var_os("OPAL_FORCE_HOME").is_some();
     #[cfg(feature = "automation")]
     let force_home = force_home || debug_cfg.as_ref().map(|c| c.force_home).unwrap_or(false);
@@ -125,6 +150,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // check, and an expired token couldn't be refreshed without an id).
         state.router.view.set(View::Setup);
     }
+    #[cfg(debug_assertions)]
+    let stress = stress.map(|cfg| {
+        state.router.view.set(View::Stress);
+        Rc::new(views::stress::StressView::new(cfg))
+    });
 
     let mut app = App::new("Opal", win_w, win_h)
         .decorations(false)
@@ -155,14 +185,35 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     let icons = std::rc::Rc::new(widgets::icon::load_all(&mut app));
     let rebuild = app.rebuild_token();
+    #[cfg(debug_assertions)]
+    let gallery = gallery_tab.map(|tab| {
+        state.router.view.set(View::Gallery);
+        Rc::new(views::gallery::GalleryView::new(tab, icons.clone(), rebuild.clone()))
+    });
     // Connect to the dx devserver for runtime hot-patching (no-op unless the
     // `hotreload` feature is on). The patch handler latches a flag + wakes the
     // loop; the per-frame tick drains it into a scene rebuild.
     hotreload::connect(app.wake_handle());
-    let worker = Rc::new(Worker::new(app.wake_handle(), app.uploader()));
+    let worker = Rc::new(Worker::new(app.wake_handle(), app.uploader(), !safe_mode));
+    // OS media overlay + hardware media keys (no-op without the
+    // `media-controls` feature). Keys wake the loop; the tick drains them.
+    let media = Rc::new(media_controls::MediaControls::new(app.wake_handle()));
+    // System-wide transport shortcuts (no-op without the `global-hotkeys`
+    // feature); presses queue like media keys and drain in the same tick.
+    let hotkeys = hotkeys::Hotkeys::new(&state.prefs.data.borrow().hotkeys, app.wake_handle());
+    // Low-power mode steps the progress bar; this wakes the loop per step.
+    state.player_ui.start_step_pulse(app.wake_handle());
     // Stored tokens can only be refreshed with the user's own client id;
     // empty when unconfigured (then an expired pair just routes to login).
-    worker.try_load_tokens(state.prefs.data.borrow().client_id().unwrap_or_default());
+    // The stress scene and the gallery stay offline: no tokens → no auth
+    // push routes away.
+    #[cfg(debug_assertions)]
+    let load_tokens = stress.is_none() && gallery.is_none();
+    #[cfg(not(debug_assertions))]
+    let load_tokens = true;
+    if load_tokens {
+        worker.try_load_tokens(state.prefs.data.borrow().client_id().unwrap_or_default());
+    }
     // Hand the state the engine's frame sink so the Canvas decode thread
     // can push video frames onto the now-playing external node.
     state.canvas.set_frame_sink(app.frame_sink());
@@ -197,6 +248,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
     };
     if show_canvas
+        && state.canvas_video()
         && let Some(uri) = last_uri
         && let Some(id) = api::track_id_from_uri(&uri)
     {
@@ -220,6 +272,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let app = {
         let state = state.clone();
+        #[cfg(debug_assertions)]
+        let stress = stress.clone();
+        #[cfg(debug_assertions)]
+        let gallery = gallery.clone();
         // Route the build through `hotreload::call`: it's the subsecond
         // re-entry point, so an applied patch re-runs the patched `view`
         // bodies on the next rebuild. Plain call-through when the feature
@@ -229,6 +285,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 View::Setup => setup_view.build(s),
                 View::Splash | View::Login => login_view.build(s),
                 View::Home => home_view.build(s),
+                #[cfg(debug_assertions)]
+                View::Stress => {
+                    if let Some(v) = &stress {
+                        v.build(s);
+                    }
+                }
+                #[cfg(debug_assertions)]
+                View::Gallery => {
+                    if let Some(v) = &gallery {
+                        v.build(s);
+                    }
+                }
             })
         })
     };
@@ -237,7 +305,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let state = state.clone();
         let worker = worker.clone();
         let rebuild = rebuild.clone();
-        app.on_frame(move |ctx, tl, now| app::frame::tick(&state, &worker, &rebuild, ctx, tl, now))
+        app.on_frame(move |ctx, tl, now| {
+            #[cfg(debug_assertions)]
+            if let Some(v) = &stress {
+                v.tick(tl, now);
+            }
+            #[cfg(debug_assertions)]
+            if let Some(v) = &gallery {
+                v.tick(tl, now);
+            }
+            app::frame::tick(&state, &worker, &media, &hotkeys, &rebuild, ctx, tl, now)
+        })
     };
 
     // Force a final prefs flush on app close — picks up any mouse-up
@@ -0,0 +1,298 @@
+//! OS media integration — MPRIS on Linux, Now Playing on macOS, System
+//! Media Transport Controls on Windows — via `souvlaki`. Feature-gated
+//! behind `media-controls` (on by default); with it off every entry point
+//! is a no-op shim, so call sites stay free of `#[cfg]`.
+//!
+//! Two directions:
+//! - **out**: [`MediaControls::publish`] mirrors the live player snapshot
+//!   (title/artist/cover/duration + play state) to the OS overlay. Called
+//!   every frame but only pushes when the track or play state changes.
+//! - **in**: hardware media keys / the OS overlay buttons arrive on the
+//!   platform's thread, are queued as [`MediaKey`]s + wake the loop, and
+//!   are drained on the UI thread by [`MediaControls::poll`] in the frame
+//!   tick, which routes them through the same dispatcher as the player bar.
+//!
+//! Windows note: SMTC must be bound to a top-level `HWND`, which the engine
+//! doesn't hand out and which only exists once the event loop is running.
+//! So on Windows the attach waits for the first frame that finds a visible
+//! window owned by the UI thread and binds to that. Any other failure is
+//! logged (fail-soft: the app runs exactly as without the feature).
+
+#[cfg(feature = "media-controls")]
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use opal_gfx::WakeHandle;
+
+use crate::api::CurrentlyPlaying;
+
+/// A transport request from the OS (media key, lock-screen / overlay
+/// button). `Play`/`Pause` are kept distinct from `Toggle` so a "play"
+/// key on an already-playing track doesn't pause it. Only the backend
+/// below (and the hotkeys, for three of them) build these, so without
+/// `media-controls` some or all variants go unconstructed.
+#[cfg_attr(not(feature = "media-controls"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaKey {
+    Play,
+    Pause,
+    Toggle,
+    Next,
+    Previous,
+}
+
+pub struct MediaControls {
+    #[cfg(feature = "media-controls")]
+    inner: RefCell<imp::Slot>,
+}
+
+impl MediaControls {
+    /// Register with the OS media service (on Windows, once the window
+    /// exists — see the module docs). `wake` re-runs the (possibly parked)
+    /// event loop when a key arrives off-thread. Never fails — an
+    /// unavailable service (no session bus) just logs.
+    pub fn new(wake: Arc<WakeHandle>) -> Self {
+        #[cfg(feature = "media-controls")]
+        {
+            Self {
+                inner: RefCell::new(imp::Slot::new(wake)),
+            }
+        }
+        #[cfg(not(feature = "media-controls"))]
+        {
+            let _ = wake;
+            Self {}
+        }
+    }
+
+    /// UI-thread drain: the next queued OS key, if any.
+    pub fn poll(&self) -> Option<MediaKey> {
+        #[cfg(feature = "media-controls")]
+        {
+            self.inner
+                .borrow_mut()
+                .get()
+                .and_then(|a| a.rx.try_recv().ok())
+        }
+        #[cfg(not(feature = "media-controls"))]
+        {
+            None
+        }
+    }
+
+    /// Mirror the live snapshot to the OS overlay. Dedups on track, artist
+    /// and play state, so the per-frame call is a compare on the cold
+    /// path; the OS interpolates position itself between pushes.
+    pub fn publish(&self, p: Option<&CurrentlyPlaying>) {
+        #[cfg(feature = "media-controls")]
+        if let Some(a) = self.inner.borrow_mut().get() {
+            a.publish(p);
+        }
+        #[cfg(not(feature = "media-controls"))]
+        let _ = p;
+    }
+}
+
+#[cfg(feature = "media-controls")]
+mod imp {
+    use std::cell::{Cell, RefCell};
+    use std::ffi::c_void;
+    use std::sync::Arc;
+    use std::sync::mpsc::{Receiver, channel};
+    use std::time::Duration;
+
+    use opal_gfx::WakeHandle;
+    use souvlaki::{MediaControlEvent, MediaMetadata, MediaPlayback, MediaPosition, PlatformConfig};
+
+    use super::MediaKey;
+    use crate::api::CurrentlyPlaying;
+
+    /// The backend's lifecycle on the UI thread.
+    pub enum Slot {
+        /// Windows: waiting for the window SMTC binds to.
+        #[cfg(windows)]
+        Pending(Arc<WakeHandle>),
+        Attached(Attached),
+        /// Attach failed (logged); stays off for the session.
+        Off,
+    }
+
+    impl Slot {
+        pub fn new(wake: Arc<WakeHandle>) -> Self {
+            #[cfg(windows)]
+            {
+                Self::Pending(wake)
+            }
+            #[cfg(not(windows))]
+            {
+                Self::attach(wake, None)
+            }
+        }
+
+        fn attach(wake: Arc<WakeHandle>, hwnd: Option<*mut c_void>) -> Self {
+            match Attached::new(wake, hwnd) {
+                Ok(a) => Self::Attached(a),
+                Err(e) => {
+                    log::warn!("media controls unavailable: {e}");
+                    Self::Off
+                }
+            }
+        }
+
+        /// The attached backend, if any — on Windows, attaching first once
+        /// the window has appeared.
+        pub fn get(&mut self) -> Option<&Attached> {
+            #[cfg(windows)]
+            if let Self::Pending(wake) = self
+                && let Some(hwnd) = window::top_level()
+            {
+                let wake = wake.clone();
+                *self = Self::attach(wake, Some(hwnd));
+            }
+            match self {
+                Self::Attached(a) => Some(a),
+                _ => None,
+            }
+        }
+    }
+
+    pub struct Attached {
+        controls: RefCell<souvlaki::MediaControls>,
+        pub rx: Receiver<MediaKey>,
+        /// Last `(track_id, artist)` / play state pushed — the publish
+        /// dedup keys, so the per-frame call allocates nothing when idle.
+        last_meta: RefCell<Option<(String, String)>>,
+        last_playing: Cell<Option<bool>>,
+    }
+
+    impl Attached {
+        fn new(wake: Arc<WakeHandle>, hwnd: Option<*mut c_void>) -> Result<Self, String> {
+            let config = PlatformConfig {
+                dbus_name: "opal",
+                display_name: "Opal",
+                hwnd,
+            };
+            let mut controls =
+                souvlaki::MediaControls::new(config).map_err(|e| format!("{e:?}"))?;
+            let (tx, rx) = channel();
+            controls
+                .attach(move |event| {
+                    let key = match event {
+                        MediaControlEvent::Play => MediaKey::Play,
+                        MediaControlEvent::Pause => MediaKey::Pause,
+                        MediaControlEvent::Toggle => MediaKey::Toggle,
+                        MediaControlEvent::Next => MediaKey::Next,
+                        MediaControlEvent::Previous => MediaKey::Previous,
+                        _ => return,
+                    };
+                    let _ = tx.send(key);
+                    wake.wake();
+                })
+                .map_err(|e| format!("{e:?}"))?;
+            Ok(Self {
+                controls: RefCell::new(controls),
+                rx,
+                last_meta: RefCell::default(),
+                last_playing: Cell::new(None),
+            })
+        }
+
+        pub fn publish(&self, p: Option<&CurrentlyPlaying>) {
+            let playing = p.map(|p| p.is_playing);
+            // Artist is part of the key: it can fill in a push after the
+            // title (the `/v1/tracks` detail lookup resolves late).
+            let meta_same = match (self.last_meta.borrow().as_ref(), p) {
+                (Some((id, artist)), Some(p)) => *id == p.track_id && *artist == p.artist,
+                (None, None) => true,
+                _ => false,
+            };
+            if meta_same && self.last_playing.get() == playing {
+                return;
+            }
+            self.last_playing.set(playing);
+            let mut controls = self.controls.borrow_mut();
+            let Some(p) = p else {
+                *self.last_meta.borrow_mut() = None;
+                let _ = controls.set_playback(MediaPlayback::Stopped);
+                return;
+            };
+            if !meta_same {
+                *self.last_meta.borrow_mut() = Some((p.track_id.clone(), p.artist.clone()));
+                let meta = MediaMetadata {
+                    title: Some(p.name.as_str()),
+                    artist: Some(p.artist.as_str()).filter(|a| !a.is_empty()),
+                    cover_url: p.album_image_url.as_deref(),
+                    duration: Some(Duration::from_millis(p.duration_ms)),
+                    ..Default::default()
+                };
+                if let Err(e) = controls.set_metadata(meta) {
+                    log::debug!("media controls: metadata push failed: {e:?}");
+                }
+            }
+            let progress = Some(MediaPosition(Duration::from_millis(p.live_progress_ms())));
+            let playback = if p.is_playing {
+                MediaPlayback::Playing { progress }
+            } else {
+                MediaPlayback::Paused { progress }
+            };
+            if let Err(e) = controls.set_playback(playback) {
+                log::debug!("media controls: playback push failed: {e:?}");
+            }
+        }
+    }
+
+    /// Finding the app's window without the engine's help: the engine
+    /// creates it on the UI thread, so it's that thread's visible
+    /// top-level window (winit's helper windows are hidden).
+    #[cfg(windows)]
+    mod window {
+        use std::ffi::c_void;
+
+        type Hwnd = *mut c_void;
+
+        #[link(name = "kernel32")]
+        unsafe extern "system" {
+            fn GetCurrentThreadId() -> u32;
+        }
+
+        #[link(name = "user32")]
+        unsafe extern "system" {
+            fn EnumThreadWindows(
+                thread: u32,
+                f: unsafe extern "system" fn(Hwnd, isize) -> i32,
+                param: isize,
+            ) -> i32;
+            fn IsWindowVisible(hwnd: Hwnd) -> i32;
+        }
+
+        /// Stops the walk at the first visible window, writing it through
+        /// `found` (an `*mut Hwnd`).
+        unsafe extern "system" fn first_visible(hwnd: Hwnd, found: isize) -> i32 {
+            // SAFETY: `hwnd` comes from the enumeration; `found` is the
+            // live local passed by `top_level` below.
+            unsafe {
+                if IsWindowVisible(hwnd) == 0 {
+                    return 1;
+                }
+                *(found as *mut Hwnd) = hwnd;
+            }
+            0
+        }
+
+        /// The UI thread's visible top-level window; `None` before the
+        /// event loop has shown it. Call on the UI thread.
+        pub fn top_level() -> Option<Hwnd> {
+            let mut found: Hwnd = std::ptr::null_mut();
+            // SAFETY: plain Win32 calls; the callback only writes `found`,
+            // which outlives the synchronous enumeration.
+            unsafe {
+                EnumThreadWindows(
+                    GetCurrentThreadId(),
+                    first_visible,
+                    &mut found as *mut Hwnd as isize,
+                );
+            }
+            (!found.is_null()).then_some(found)
+        }
+    }
+}
@@ -71,6 +71,15 @@ impl AuthModel {
         Some(rt)
     }
 
+    /// Pull the proactive refresh forward to now (e.g. after a resume from
+    /// sleep, when the stored deadline can't be trusted). No-op while
+    /// signed out or with a refresh already in flight.
+    pub fn refresh_now(&self, now: Instant) {
+        if self.refresh_at.get().is_some() && !self.refresh_inflight.get() {
+            self.refresh_at.set(Some(now));
+        }
+    }
+
     /// A refresh attempt failed — back off and try again shortly.
     pub fn refresh_failed(&self) {
         self.refresh_at.set(Some(Instant::now() + REFRESH_RETRY));
@@ -7,11 +7,13 @@
 //! image-handle binds and drives the tweens through the `Timeline`, so a
 //! track change cross-dissolves with **no scene rebuild**.
 
+use std::cell::Cell;
 use std::time::{Duration, Instant};
 
 use opal_gfx::{Curve, ImageHandle, Signal, Timeline};
 
-use crate::widgets::tokens;
+use crate::widgets::color::mix_oklch;
+use crate::widgets::{motion, tokens};
 
 /// How long the backdrop crossfade + accent colour transition takes on
 /// track change. An ambient cross-dissolve — the previous cover fades
@@ -24,6 +26,9 @@ const CROSSFADE_DURATION: Duration = Duration::from_millis(1500);
 /// big blurred backdrop + accent catch up behind them.
 const PANEL_CROSSFADE_DURATION: Duration = Duration::from_millis(450);
 
+/// How far past 1.0 the accent tween runs (see [`BackdropModel::tick_accent`]).
+const ACCENT_OVERRUN: f32 = 1.1;
+
 pub struct BackdropModel {
     /// Outgoing backdrop layer — the previous track's art, held opaque
     /// under the incoming layer so the dissolve has full coverage (no
@@ -45,6 +50,13 @@ pub struct BackdropModel {
     /// Always contrast-safe over the dark chrome — chosen/lifted at the
     /// worker (`color::chrome_accent` / `lift_for_chrome`).
     pub accent: Signal<[f32; 4]>,
+    /// The accent re-tint in flight: `accent` is blended from
+    /// `accent_from` to `accent_to` in OKLCH by the frame tick, off the
+    /// linear `accent_t` — the timeline's own colour tween is a channel
+    /// lerp, which dulls most hue changes to brown/grey mid-way.
+    accent_from: Cell<[f32; 4]>,
+    accent_to: Cell<[f32; 4]>,
+    accent_t: Signal<f32>,
     /// Mean luminance of the current cover — how bright the blurred
     /// ambient backdrop reads. Drives the adaptive glass dim (bright art
     /// gets a stronger tint so the chrome on top keeps contrast). Rides
@@ -61,6 +73,9 @@ impl BackdropModel {
             crossfade_t: Signal::new(1.0),
             panel_t: Signal::new(1.0),
             accent: Signal::new(tokens::ACCENT),
+            accent_from: Cell::new(tokens::ACCENT),
+            accent_to: Cell::new(tokens::ACCENT),
+            accent_t: Signal::new(1.0),
             art_luma: Signal::new(0.0),
         }
     }
@@ -86,25 +101,51 @@ impl BackdropModel {
         tl: &mut Timeline,
         now: Instant,
     ) {
+        let fade = motion::dur(CROSSFADE_DURATION);
         let current = self.curr.get();
         if current != Some(next) {
             self.prev.set(current);
             self.curr.set(Some(next));
             self.crossfade_t.set(0.0);
-            tl.animate(&self.crossfade_t, 1.0, Curve::EaseInOut, CROSSFADE_DURATION, now);
+            tl.animate(&self.crossfade_t, 1.0, Curve::EaseInOut, fade, now);
             self.panel_t.set(0.0);
-            tl.animate(&self.panel_t, 1.0, Curve::EaseInOut, PANEL_CROSSFADE_DURATION, now);
+            let panel = motion::dur(PANEL_CROSSFADE_DURATION);
+            tl.animate(&self.panel_t, 1.0, Curve::EaseInOut, panel, now);
         }
-        tl.animate(&self.art_luma, luma, Curve::EaseInOut, CROSSFADE_DURATION, now);
+        tl.animate(&self.art_luma, luma, Curve::EaseInOut, fade, now);
         if let Some(c) = accent {
-            tl.animate(&self.accent, c, Curve::EaseInOut, PANEL_CROSSFADE_DURATION, now);
+            self.set_accent(c, tl, now);
         }
     }
 
     /// Tween only the accent — a late `AccentReady` overriding the
-    /// provisional pixel-average with Spotify's exact colour.
+    /// provisional pixel-average with Spotify's exact colour. Starts from
+    /// whatever is showing, so an interrupted re-tint carries on smoothly.
     pub fn set_accent(&self, accent: [f32; 4], tl: &mut Timeline, now: Instant) {
-        tl.animate(&self.accent, accent, Curve::EaseInOut, PANEL_CROSSFADE_DURATION, now);
+        if accent == self.accent_to.get() {
+            return;
+        }
+        self.accent_from.set(self.accent.get());
+        self.accent_to.set(accent);
+        self.accent_t.set(0.0);
+        let run = motion::dur(PANEL_CROSSFADE_DURATION).mul_f32(ACCENT_OVERRUN);
+        tl.animate(&self.accent_t, ACCENT_OVERRUN, Curve::Linear, run, now);
+    }
+
+    /// Per-frame: blend `accent` along the in-flight re-tint. The tween
+    /// runs past 1.0 so the loop is still awake on the frame that lands
+    /// exactly on the target; settled, this is a no-op.
+    pub fn tick_accent(&self) {
+        let to = self.accent_to.get();
+        let t = self.accent_t.get();
+        if t >= 1.0 {
+            if self.accent.get() != to {
+                self.accent.set(to);
+            }
+            return;
+        }
+        let eased = t * t * (3.0 - 2.0 * t);
+        self.accent.set(mix_oklch(self.accent_from.get(), to, eased));
     }
 }
 
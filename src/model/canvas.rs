@@ -20,6 +20,7 @@ use opal_gfx::node::NodeId;
 use opal_gfx::{Curve, FrameSink, ImageHandle, Signal, Timeline};
 
 use crate::api::{CurrentlyPlaying, track_id_from_uri};
+use crate::widgets::motion;
 use crate::worker::Worker;
 
 /// Resting alpha of the dark overlay over the Canvas video (dimmed until
@@ -31,11 +32,17 @@ const CANVAS_DIM_DURATION: Duration = Duration::from_millis(280);
 
 /// A running Canvas-video decode: the track it's decoding and the flag
 /// the decode thread polls so a track change (or canvas-off) can stop it.
+/// Only the real `start_decode` builds one, so without `canvas-video` the
+/// `decode` slot is always empty.
+#[cfg_attr(not(feature = "canvas-video"), allow(dead_code))]
 struct CanvasSession {
     track_id: String,
     stop: Arc<AtomicBool>,
 }
 
+// Without `canvas-video` the decode-only fields (sink, node, epoch) are
+// installed but never read.
+#[cfg_attr(not(feature = "canvas-video"), allow(dead_code))]
 pub struct CanvasModel {
     /// Whether to show the looping Canvas video in now-playing (persisted
     /// via prefs; toggled in settings; consumed here).
@@ -185,7 +192,8 @@ impl CanvasModel {
         if hov != self.hover_last.get() {
             self.hover_last.set(hov);
             let target = if hov { 0.0 } else { CANVAS_DIM_ALPHA };
-            tl.animate(&self.dim, target, Curve::EaseInOut, CANVAS_DIM_DURATION, now);
+            let d = motion::dur(CANVAS_DIM_DURATION);
+            tl.animate(&self.dim, target, Curve::EaseInOut, d, now);
         }
     }
 
@@ -198,6 +206,7 @@ impl CanvasModel {
     /// `FrameSink`, targeting [`node`](Self::node) read fresh each frame so
     /// it follows rebuilds. No-op if already decoding this track or the
     /// frame sink isn't installed yet.
+    #[cfg(feature = "canvas-video")]
     pub fn start_decode(&self, track_id: String, path: std::path::PathBuf) {
         if self
             .decode
@@ -328,6 +337,11 @@ impl CanvasModel {
         }
     }
 
+    /// Built without the `canvas-video` feature: there is no decoder, so
+    /// the now-playing pane keeps the static cover.
+    #[cfg(not(feature = "canvas-video"))]
+    pub fn start_decode(&self, _track_id: String, _path: std::path::PathBuf) {}
+
     /// React to the `show_canvas` toggle flipping. Turned on mid-track:
     /// decode the cached clip if we have it, else fetch it for the current
     /// track. Turned off: stop decoding + drop the video texture. The
@@ -16,8 +16,9 @@ use opal_gfx::Signal;
 
 use crate::album_art;
 use crate::api::{AlbumRef, HomeData, PlaylistDetail, PlaylistTrack};
+use crate::format;
 use crate::model::ArtModel;
-use crate::views::home::playlist::{self, PlaylistRow, RowBuf};
+use crate::views::home::playlist::{PlaylistRow, RowBuf};
 use crate::worker::Worker;
 
 /// How long a cached playlist stays fresh before a re-open re-fetches it.
@@ -158,7 +159,8 @@ impl LibraryModel {
                 title: t.name.clone(),
                 artist: t.artist.clone(),
                 album: t.album.clone(),
-                duration: playlist::fmt_duration(t.duration_ms),
+                duration: format::clock(t.duration_ms),
+                duration_ms: t.duration_ms,
                 uri: t.uri.clone(),
                 art: cover,
                 cover_url: t.album_image_url.clone(),
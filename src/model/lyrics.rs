@@ -0,0 +1,142 @@
+//! Lyrics slice: the current track's lyrics (fetched while the lyrics
+//! page is open) + the highlighted line.
+//!
+//! [`LyricsModel::tick`] runs each frame with the live playback position
+//! and moves [`LyricsModel::active`] — a reactive bind, so the highlight
+//! follows the song without a rebuild — and reports the new line so the
+//! frame loop can scroll it into view.
+//!
+//! Nothing goes to LRCLIB until the user opts in ([`LyricsModel::lookup`],
+//! persisted as `prefs.lyrics_lookup`); until then the page explains what
+//! the lookup would send.
+
+use std::cell::RefCell;
+
+use opal_gfx::Signal;
+
+use crate::api::{CurrentlyPlaying, track_id_from_uri};
+use crate::lyrics::{self, Lyrics};
+use crate::worker::Worker;
+
+/// What the page has for the current track.
+pub enum LyricsState {
+    /// Lookup not opted into — nothing was sent.
+    Off,
+    Loading,
+    /// No match on LRCLIB.
+    Missing,
+    /// The lookup failed (network, server). Retried on the next open.
+    Failed,
+    Ready(Lyrics),
+}
+
+pub struct LyricsModel {
+    /// Track the state below belongs to (bare id).
+    track_id: RefCell<String>,
+    pub state: RefCell<LyricsState>,
+    /// Index of the line playing now (synced lyrics only).
+    pub active: Signal<Option<usize>>,
+    /// "Look up lyrics" toggle — seeded from prefs, drives the settings
+    /// switch; [`Self::ensure`] sends nothing while it's off.
+    pub lookup: Signal<bool>,
+}
+
+impl LyricsModel {
+    pub fn new(lookup: bool) -> Self {
+        Self {
+            track_id: RefCell::new(String::new()),
+            state: RefCell::new(if lookup { LyricsState::Missing } else { LyricsState::Off }),
+            active: Signal::new(None),
+            lookup: Signal::new(lookup),
+        }
+    }
+
+    /// Fetch lyrics for the playing track unless they're already loaded or
+    /// in flight, or lookup is off. Called on opening the page, on track
+    /// change while it's open, and when lookup is switched on.
+    pub fn ensure(&self, worker: &Worker, playing: &CurrentlyPlaying) {
+        if !self.lookup.get() {
+            self.track_id.borrow_mut().clear();
+            *self.state.borrow_mut() = LyricsState::Off;
+            self.active.set(None);
+            return;
+        }
+        let Some(id) = track_id_from_uri(&playing.track_id) else {
+            return;
+        };
+        if *self.track_id.borrow() == id {
+            return;
+        }
+        *self.track_id.borrow_mut() = id.to_string();
+        *self.state.borrow_mut() = LyricsState::Loading;
+        self.active.set(None);
+        // LRCLIB indexes the primary artist; ours is a ", "-joined credit.
+        let artist = playing.artist.split(", ").next().unwrap_or_default();
+        worker.fetch_lyrics(
+            id.to_string(),
+            playing.name.clone(),
+            artist.to_string(),
+            playing.duration_ms,
+        );
+    }
+
+    /// Store a worker response. `false` (dropped) when the track moved on.
+    /// A failed lookup forgets the track so the next [`ensure`](Self::ensure)
+    /// asks again instead of treating it as settled.
+    pub fn apply(&self, track_id: &str, found: Result<Option<Lyrics>, String>) -> bool {
+        if *self.track_id.borrow() != track_id {
+            return false;
+        }
+        *self.state.borrow_mut() = match found {
+            Ok(Some(l)) => LyricsState::Ready(l),
+            Ok(None) => LyricsState::Missing,
+            Err(_) => {
+                self.track_id.borrow_mut().clear();
+                LyricsState::Failed
+            }
+        };
+        true
+    }
+
+    /// Per-frame highlight update at playback position `pos_ms`. Returns
+    /// the newly active line when it changed.
+    pub fn tick(&self, pos_ms: u64) -> Option<usize> {
+        let next = match &*self.state.borrow() {
+            LyricsState::Ready(l) if l.synced => lyrics::active_line(&l.lines, pos_ms),
+            _ => None,
+        };
+        if next == self.active.get() {
+            return None;
+        }
+        self.active.set(next);
+        next
+    }
+}
+
+impl Default for LyricsModel {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_lookup_is_not_settled() {
+        let m = LyricsModel::new(true);
+        *m.track_id.borrow_mut() = "abc".into();
+        assert!(m.apply("abc", Err("timed out".into())));
+        assert!(matches!(*m.state.borrow(), LyricsState::Failed));
+        // Forgotten, so the next `ensure` for "abc" fetches again; a late
+        // answer for it is dropped meanwhile.
+        assert!(m.track_id.borrow().is_empty());
+        assert!(!m.apply("abc", Ok(None)));
+
+        *m.track_id.borrow_mut() = "abc".into();
+        assert!(m.apply("abc", Ok(None)));
+        assert!(matches!(*m.state.borrow(), LyricsState::Missing));
+        assert_eq!(*m.track_id.borrow(), "abc");
+    }
+}
@@ -49,6 +49,9 @@ pub struct MembershipModel {
     pub playlists: RefCell<Vec<MembershipPlaylist>>,
     /// Index loaded/built this session (picker shows a spinner until then).
     pub ready: Cell<bool>,
+    /// How far the first scan has got, 0..=1 — the picker's progress ring
+    /// once the worker reports it (read live, no rebuild per step).
+    pub scan: Signal<f32>,
     /// The current track's playlist ids — drives the picker checkboxes.
     pub current: RefCell<HashSet<String>>,
     /// Current track is in ≥1 playlist; combined with `liked` for the heart
@@ -69,6 +72,7 @@ impl MembershipModel {
         Self {
             playlists: RefCell::default(),
             ready: Cell::new(false),
+            scan: Signal::new(0.0),
             current: RefCell::default(),
             in_playlist: Signal::new(false),
             hint: TextSignal::new(""),
@@ -82,6 +86,14 @@ impl MembershipModel {
         *self.target.borrow_mut() = target;
     }
 
+    /// Record scan progress. Returns whether this is the first report, so
+    /// an open picker can swap its spinner for the ring.
+    pub fn set_scan(&self, scanned: usize, total: usize) -> bool {
+        let first = self.scan.get() == 0.0;
+        self.scan.set(scanned as f32 / total.max(1) as f32);
+        first
+    }
+
     /// Apply the loaded/refreshed playlist list (the index landed).
     pub fn set_playlists(&self, playlists: Vec<MembershipPlaylist>) {
         *self.playlists.borrow_mut() = playlists;
@@ -122,9 +134,10 @@ impl MembershipModel {
     pub fn rebuild_hint(&self, liked: bool) {
         let cur = self.current.borrow();
         let names = self.playlists.borrow();
+        let liked_songs = crate::i18n::tr("player.liked_songs");
         let mut parts: Vec<&str> = Vec::new();
         if liked {
-            parts.push("Liked Songs");
+            parts.push(&liked_songs);
         }
         for p in names.iter() {
             if cur.contains(&p.id) {
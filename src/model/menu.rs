@@ -1,22 +1,26 @@
 //! Right-click context-menu slice.
 //!
 //! Holds the open state, the logical-px position to anchor the menu at
-//! (the cursor), and the right-clicked track's actionable data. Opening
-//! requests a scene rebuild (like the other popups), so the menu renders
-//! at the new position with the new target's actions; dismissing closes
-//! it the same way.
+//! (the cursor), and the right-clicked track's (or library playlist's)
+//! actionable data. Opening requests a scene rebuild (like the other
+//! popups), so the menu renders at the new position with the new target's
+//! actions; dismissing closes it the same way.
 
 use std::cell::{Cell, RefCell};
 
-/// The right-clicked track's data the menu acts on.
+/// The right-clicked row's data the menu acts on.
 #[derive(Clone, Default)]
 pub struct MenuTarget {
-    /// `spotify:track:…` URI — Add to queue.
+    /// `spotify:track:…` URI — Add to queue (empty hides the item).
     pub uri: String,
     /// Album id — "Go to album" (empty hides the item).
     pub album_id: String,
     /// First-artist id — "Go to artist" (empty hides the item).
     pub artist_id: String,
+    /// Library playlist id — "Warm up on launch" (empty hides the item).
+    pub playlist_id: String,
+    /// Whether `playlist_id` is already marked for warm-up.
+    pub warm: bool,
 }
 
 pub struct MenuModel {
@@ -24,6 +28,10 @@ pub struct MenuModel {
     /// Anchor position in **logical px** (cursor at right-click time).
     pub pos: Cell<[f32; 2]>,
     pub target: RefCell<MenuTarget>,
+    /// Window size in **logical px**, mirrored by the frame tick so the
+    /// menu can flip inward when the cursor sits near the right/bottom
+    /// edge instead of spilling off-window.
+    pub bounds: Cell<[f32; 2]>,
 }
 
 impl MenuModel {
@@ -32,6 +40,7 @@ impl MenuModel {
             open: Cell::new(false),
             pos: Cell::new([0.0; 2]),
             target: RefCell::default(),
+            bounds: Cell::new([f32::MAX; 2]),
         }
     }
 
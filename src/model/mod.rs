@@ -17,10 +17,16 @@
 //!   - [`backdrop`] — album-art backdrop + accent crossfade.
 //!   - [`canvas`] — Spotify Canvas video decode + dim/hover.
 //!   - [`library`] — Home feed data + playlist loading/caching.
+//!   - [`lyrics`] — current track's lyrics + the highlighted line.
 //!   - [`player`] — reactive player-chrome + authoritative snapshot.
+//!   - [`power`] — low-power rendering (reduced effects) + battery poll.
 //!   - [`prefs`] — persisted preferences + panel widths + debounced save.
+//!   - [`responsive`] — window-width breakpoints folding the side panels.
 //!   - [`router`] — view + centre-pane nav + entrance transition.
+//!   - [`search`] — search field debounce + latest results.
 //!   - [`settings`] — settings modal overlay + cache usage + dir handoff.
+//!   - [`suspend`] — suspend/resume detection + post-resume resync gate.
+//!   - [`toast`] — transient corner notifications + their lifecycle.
 
 pub mod art;
 pub mod auth;
@@ -28,12 +34,18 @@ pub mod backdrop;
 pub mod canvas;
 pub mod devices;
 pub mod library;
+pub mod lyrics;
 pub mod membership;
 pub mod menu;
 pub mod player;
+pub mod power;
 pub mod prefs;
+pub mod responsive;
 pub mod router;
+pub mod search;
 pub mod settings;
+pub mod suspend;
+pub mod toast;
 
 pub use art::ArtModel;
 pub use auth::AuthModel;
@@ -41,9 +53,15 @@ pub use backdrop::BackdropModel;
 pub use canvas::CanvasModel;
 pub use devices::DevicesModel;
 pub use library::LibraryModel;
+pub use lyrics::LyricsModel;
 pub use membership::{MembershipModel, MembershipTarget};
 pub use menu::{MenuModel, MenuTarget};
 pub use player::PlayerModel;
+pub use power::PowerModel;
 pub use prefs::PrefsModel;
+pub use responsive::ResponsiveModel;
 pub use router::RouterModel;
+pub use search::SearchModel;
 pub use settings::SettingsModel;
+pub use suspend::SuspendModel;
+pub use toast::ToastModel;
@@ -11,11 +11,98 @@
 //! [`crate::api::CurrentlyPlaying`] snapshot still lives on the app state.
 
 use std::cell::{Cell, RefCell};
+use std::sync::{Arc, Condvar, Mutex};
 use std::time::{Duration, Instant};
 
-use opal_gfx::{Curve, Signal, TextSignal, Timeline};
+use opal_gfx::{Curve, Signal, TextSignal, Timeline, WakeHandle};
 
 use crate::api::{CurrentlyPlaying, RepeatMode};
+use crate::format;
+
+/// Longest gap between two deliveries of the same media key that still
+/// reads as one burst: a held key's auto-repeat (~25–40 Hz) or a desktop
+/// delivering one press twice. The OS media-key layer reports no
+/// releases, so cadence is the only sign of a hold.
+pub const KEY_REPEAT_GAP: Duration = Duration::from_millis(60);
+
+/// Quiet time after which the same media key is certainly a new press —
+/// longer than the OS typematic delay (250–600 ms) a held key waits
+/// before it starts repeating. A same-key delivery sooner than this may
+/// be a quick second press *or* a hold's first repeat, so the guard
+/// holds it back for [`KEY_REPEAT_GAP`] to see whether a burst follows.
+pub const KEY_HOLD_DELAY: Duration = Duration::from_millis(700);
+
+/// Progress step while stepped (low-power): the bar and the elapsed
+/// label move once a second instead of the tween redrawing every frame.
+const STEP_EVERY: Duration = Duration::from_secs(1);
+
+/// A transport intent raised by a player-bar button click or an OS media
+/// key. The consumer maps these to optimistic signal flips + worker
+/// commands; the UI layer stays ignorant of tokens and the Web API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerAction {
+    PlayPause,
+    /// Explicit play / pause (media keys, the OS overlay). Unlike
+    /// `PlayPause` these are idempotent: a Play while playing is dropped.
+    Play,
+    Pause,
+    Next,
+    Prev,
+    ToggleShuffle,
+    CycleRepeat,
+    /// Commit the volume slider's released position (0..=100).
+    SetVolume(u8),
+}
+
+/// Where a transport intent came from. There is no tray or CLI source:
+/// Opal has no tray icon and takes no transport commands on the command
+/// line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionSource {
+    /// A player-bar control (button, slider, wheel).
+    UiButton,
+    /// An OS media key or overlay button (see [`crate::media_controls`]).
+    MediaKey,
+    /// A global shortcut (see [`crate::hotkeys`]). Held-key repeats are
+    /// already dropped at the source, which sees releases.
+    Hotkey,
+    /// Another Spotify Connect device, seen through a cluster push (see
+    /// [`PlayerModel::remote_intents`]). Never dispatched — the change
+    /// has already happened; the tag attributes it.
+    Remote,
+}
+
+/// What the media-key repeat guard makes of one delivery (see
+/// [`PlayerModel::admit_media_key`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyVerdict {
+    /// A fresh press — dispatch it now.
+    Admit,
+    /// Part of a burst (a held key's repeats, a doubled delivery).
+    Drop,
+    /// Too soon after the same key to tell a second press from a hold's
+    /// first repeat. [`PlayerModel::settled_media_key`] releases it once
+    /// [`KEY_REPEAT_GAP`] passes without the burst that marks a hold.
+    Defer,
+}
+
+/// A media key the repeat guard is holding back: its action, the latest
+/// delivery of that key, and how many burst repeats have followed it.
+#[derive(Debug, Clone, Copy)]
+struct HeldKey {
+    action: PlayerAction,
+    last: Instant,
+    repeats: u8,
+}
+
+/// A [`PlayerAction`] tagged with its [`ActionSource`] — what the
+/// dispatcher receives, so conflict policy and logging can tell a click
+/// from a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerIntent {
+    pub action: PlayerAction,
+    pub source: ActionSource,
+}
 
 pub struct PlayerModel {
     pub title: TextSignal,
@@ -86,6 +173,24 @@ pub struct PlayerModel {
     /// play button know nothing is actually playing yet, so it resumes the
     /// last track explicitly instead of a bare Web API resume that no-ops.
     pub live: Cell<bool>,
+    /// Last media-key action delivered (admitted or not) + when — the
+    /// repeat guard in [`Self::admit_media_key`].
+    last_media_key: Cell<Option<(PlayerAction, Instant)>>,
+    /// A deferred media key awaiting [`Self::settled_media_key`].
+    held_media_key: Cell<Option<HeldKey>>,
+    /// Throwaway signal anchoring a timeline tween that keeps the loop
+    /// awake until a held-back media key settles. Value never read.
+    held_key_anchor: Signal<f32>,
+    /// Low-power stepping (see [`Self::set_stepped`]): progress is set
+    /// from `step_from` once per [`STEP_EVERY`] instead of tweened.
+    stepped: Cell<bool>,
+    /// Where progress was last anchored while playing (fraction, when).
+    /// `None` holds the bar: paused, stopped, or a seek awaiting its push.
+    step_from: Cell<Option<(f32, Instant)>>,
+    /// Stepped *and* playing — the pulse thread that wakes the loop for
+    /// each step parks on the condvar while it's false (see
+    /// [`Self::start_step_pulse`]).
+    pulse: Arc<(Mutex<bool>, Condvar)>,
 }
 
 impl PlayerModel {
@@ -116,8 +221,8 @@ impl PlayerModel {
             bar_hovered: Signal::new(false),
             seeking: Signal::new(false),
             seek_label: TextSignal::new("0:00"),
-            elapsed_label: TextSignal::new(fmt_ms(progress_ms.min(duration_ms) as u32).as_str()),
-            total_label: TextSignal::new(fmt_ms(duration_ms as u32).as_str()),
+            elapsed_label: TextSignal::new(format::clock(progress_ms.min(duration_ms)).as_str()),
+            total_label: TextSignal::new(format::clock(duration_ms).as_str()),
             last_elapsed_secs: Cell::new(u32::MAX),
             seek_held_last: Cell::new(false),
             volume: Signal::new(volume.clamp(0.0, 1.0)),
@@ -131,6 +236,12 @@ impl PlayerModel {
             )),
             snapshot: RefCell::new(None),
             live: Cell::new(false),
+            last_media_key: Cell::new(None),
+            held_media_key: Cell::new(None),
+            held_key_anchor: Signal::new(0.0),
+            stepped: Cell::new(false),
+            step_from: Cell::new(None),
+            pulse: Arc::new((Mutex::new(false), Condvar::new())),
         }
     }
 
@@ -139,7 +250,7 @@ impl PlayerModel {
     pub fn set_volume_ui(&self, frac: f32) {
         let frac = frac.clamp(0.0, 1.0);
         self.volume.set(frac);
-        self.vol_label.set(fmt_pct(frac).as_str());
+        self.vol_label.set(format::percent(frac).as_str());
     }
 
     /// Cloneable write-handle for the volume slider's `'static` event
@@ -158,7 +269,8 @@ impl PlayerModel {
     /// bumps what changed. Progress snaps to the live position, then (if
     /// playing) tweens to 1.0 over the remaining duration so the bar
     /// advances smoothly between cluster pushes; paused stops the tween so
-    /// the bar holds.
+    /// the bar holds. Stepped, the tween is skipped and
+    /// [`Self::tick_stepped`] advances from the same anchor.
     pub fn sync(&self, p: &CurrentlyPlaying, tl: &mut Timeline, now: Instant) {
         self.live.set(true);
         self.title.set(p.name.as_str());
@@ -167,7 +279,7 @@ impl PlayerModel {
         self.shuffle.set(p.shuffle);
         self.repeat_on.set(!matches!(p.repeat, RepeatMode::Off));
         self.duration_ms.set(p.duration_ms as f32);
-        self.total_label.set(fmt_ms(p.duration_ms as u32).as_str());
+        self.total_label.set(format::clock(p.duration_ms).as_str());
 
         let live = p.live_progress_ms().min(p.duration_ms);
         let frac = if p.duration_ms > 0 {
@@ -177,17 +289,122 @@ impl PlayerModel {
         };
         self.progress.set(frac);
         if p.is_playing && p.duration_ms > 0 {
-            let remaining = p.duration_ms.saturating_sub(live);
-            tl.animate(&self.progress, 1.0, Curve::Linear, Duration::from_millis(remaining), now);
+            self.anchor(Some((frac, now)));
+            if self.stepped.get() {
+                tl.stop_for(&self.progress);
+            } else {
+                let remaining = p.duration_ms.saturating_sub(live);
+                let d = Duration::from_millis(remaining);
+                tl.animate(&self.progress, 1.0, Curve::Linear, d, now);
+            }
         } else {
+            self.anchor(None);
             tl.stop_for(&self.progress);
         }
     }
 
+    /// What a cluster push changed that we didn't, tagged
+    /// [`ActionSource::Remote`]. The chrome already carries our own
+    /// optimistic flips, so a play state, shuffle or repeat that differs
+    /// from it is another device's doing. Track changes aren't attributed:
+    /// a track running out looks the same as a remote skip. Empty before
+    /// the first live push — the restored seed isn't anyone's action.
+    pub fn remote_intents(&self, p: &CurrentlyPlaying) -> Vec<PlayerIntent> {
+        if !self.live.get() {
+            return Vec::new();
+        }
+        let mut actions = Vec::new();
+        if p.is_playing != self.is_playing.get() {
+            actions.push(if p.is_playing {
+                PlayerAction::Play
+            } else {
+                PlayerAction::Pause
+            });
+        }
+        if p.shuffle != self.shuffle.get() {
+            actions.push(PlayerAction::ToggleShuffle);
+        }
+        if !matches!(p.repeat, RepeatMode::Off) != self.repeat_on.get() {
+            actions.push(PlayerAction::CycleRepeat);
+        }
+        actions
+            .into_iter()
+            .map(|action| PlayerIntent {
+                action,
+                source: ActionSource::Remote,
+            })
+            .collect()
+    }
+
+    /// Switch between the per-frame progress tween and low-power stepping,
+    /// carrying the bar's current position across. No-op when unchanged.
+    pub fn set_stepped(&self, on: bool, tl: &mut Timeline, now: Instant) {
+        if self.stepped.replace(on) == on {
+            return;
+        }
+        let frac = self.progress.get();
+        let from = self.step_from.get().map(|_| (frac, now));
+        self.anchor(from);
+        if from.is_none() {
+            return;
+        }
+        if on {
+            tl.stop_for(&self.progress);
+        } else {
+            let remaining = (1.0 - frac).max(0.0) * self.duration_ms.get();
+            let d = Duration::from_millis(remaining as u64);
+            tl.animate(&self.progress, 1.0, Curve::Linear, d, now);
+        }
+    }
+
+    /// Stepped mode, per frame: set progress from the anchor. Frames come
+    /// from input and the pulse thread, so this lands about once a second.
+    pub fn tick_stepped(&self, now: Instant) {
+        let dur = self.duration_ms.get();
+        if self.stepped.get()
+            && dur > 0.0
+            && let Some((frac, at)) = self.step_from.get()
+        {
+            let ran = now.saturating_duration_since(at).as_millis() as f32;
+            self.progress.set((frac + ran / dur).min(1.0));
+        }
+    }
+
+    /// Wake the loop every [`STEP_EVERY`] while stepping a playing track —
+    /// nothing else animates the bar then. Started once, at launch; the
+    /// thread sleeps on the pulse condvar whenever stepping is off or the
+    /// track is paused, so it costs nothing outside low-power playback.
+    pub fn start_step_pulse(&self, wake: Arc<WakeHandle>) {
+        let pulse = self.pulse.clone();
+        std::thread::spawn(move || {
+            let (on, cvar) = &*pulse;
+            loop {
+                drop(cvar.wait_while(on.lock().unwrap(), |run| !*run).unwrap());
+                std::thread::sleep(STEP_EVERY);
+                if *on.lock().unwrap() {
+                    wake.wake();
+                }
+            }
+        });
+    }
+
+    /// Move the progress anchor and keep the pulse flag in step with it,
+    /// unparking the pulse thread on the rising edge.
+    fn anchor(&self, from: Option<(f32, Instant)>) {
+        self.step_from.set(from);
+        let (on, cvar) = &*self.pulse;
+        let run = self.stepped.get() && from.is_some();
+        let mut flag = on.lock().unwrap();
+        if !std::mem::replace(&mut *flag, run) && run {
+            cvar.notify_one();
+        }
+    }
+
     /// Nothing playing on any device. Don't wipe the chrome to a dash —
     /// keep the last track visible, just mark stopped and freeze the bar.
     pub fn stopped(&self, tl: &mut Timeline) {
         self.is_playing.set(false);
+        self.anchor(None);
         tl.stop_for(&self.progress);
     }
 
@@ -198,6 +415,71 @@ impl PlayerModel {
     // push corrects the real state shortly after. Returning bool/RepeatMode
     // (not a worker command type) keeps this slice free of `worker`.
 
+    /// Media-key repeat guard. A key is admitted at once when it differs
+    /// from the last one or follows it after more than [`KEY_HOLD_DELAY`]
+    /// of quiet; dropped when it lands within [`KEY_REPEAT_GAP`] of the
+    /// last (a burst); and deferred in between. A held key thus acts once:
+    /// the press is admitted, the first auto-repeat (after the typematic
+    /// delay) is deferred, and the repeat burst behind it cancels it. A
+    /// deliberate double press is deferred too, and goes through once no
+    /// burst follows.
+    pub fn admit_media_key(&self, action: PlayerAction, now: Instant) -> KeyVerdict {
+        let since = self
+            .last_media_key
+            .get()
+            .filter(|(last, _)| *last == action)
+            .map(|(_, at)| now.saturating_duration_since(at));
+        self.last_media_key.set(Some((action, now)));
+        match since {
+            Some(gap) if gap < KEY_REPEAT_GAP => {
+                // One extra delivery is a doubled press; a second is a
+                // hold's repeat stream, so the held-back key was no press.
+                if let Some(mut held) = self.held_media_key.get().filter(|h| h.action == action) {
+                    held.last = now;
+                    held.repeats += 1;
+                    self.held_media_key.set((held.repeats < 2).then_some(held));
+                }
+                KeyVerdict::Drop
+            }
+            Some(gap) if gap < KEY_HOLD_DELAY => {
+                self.held_media_key.set(Some(HeldKey {
+                    action,
+                    last: now,
+                    repeats: 0,
+                }));
+                KeyVerdict::Defer
+            }
+            _ => KeyVerdict::Admit,
+        }
+    }
+
+    /// The deferred media key, once [`KEY_REPEAT_GAP`] has passed since its
+    /// last delivery with no hold detected — a real second press. Polled
+    /// by the frame tick before it drains new keys.
+    pub fn settled_media_key(&self, now: Instant) -> Option<PlayerAction> {
+        let held = self.held_media_key.get()?;
+        if now.saturating_duration_since(held.last) < KEY_REPEAT_GAP {
+            return None;
+        }
+        self.held_media_key.set(None);
+        Some(held.action)
+    }
+
+    /// Keep the loop firing until the held-back media key can settle —
+    /// a media key wakes the loop once, and nothing else may follow it.
+    /// Run after the frame's keys are drained; idempotent (`animate` on
+    /// the same signal replaces the in-flight tween).
+    pub fn tick_held_media_key(&self, tl: &mut Timeline, now: Instant) {
+        let Some(held) = self.held_media_key.get() else {
+            tl.stop_for(&self.held_key_anchor);
+            return;
+        };
+        let remaining =
+            (held.last + KEY_REPEAT_GAP).saturating_duration_since(now) + Duration::from_millis(10);
+        self.held_key_anchor.set(0.0);
+        tl.animate(&self.held_key_anchor, 1.0, Curve::Linear, remaining, now);
+    }
+
     /// Toggle play/pause optimistically; returns whether it **was** playing
     /// (so the host sends Pause if it was, else Play).
     pub fn toggle_play(&self) -> bool {
@@ -241,7 +523,7 @@ impl PlayerModel {
         let secs = (self.progress.get() * self.duration_ms.get() / 1000.0).max(0.0) as u32;
         if secs != self.last_elapsed_secs.get() {
             self.last_elapsed_secs.set(secs);
-            self.elapsed_label.set(fmt_ms(secs * 1000).as_str());
+            self.elapsed_label.set(format::clock(u64::from(secs) * 1000).as_str());
         }
     }
 
@@ -272,6 +554,7 @@ impl PlayerModel {
             let dur = self.duration_ms.get();
             if dur > 0.0 {
                 self.progress.set(frac);
+                self.anchor(None);
                 tl.stop_for(&self.progress);
                 return Some((frac * dur) as u32);
             }
@@ -303,7 +586,7 @@ impl SeekHandle {
         self.preview.set(frac);
         self.preview_px.set(x_rel.clamp(0.0, w));
         let ms = (frac * self.duration_ms.get()) as u32;
-        self.label.set(fmt_ms(ms).as_str());
+        self.label.set(format::clock(u64::from(ms)).as_str());
     }
 }
 
@@ -324,7 +607,7 @@ impl VolumeHandle {
         let w = lane_w.max(1.0);
         let frac = (x_rel / w).clamp(0.0, 1.0);
         self.preview_px.set(x_rel.clamp(0.0, w));
-        self.label.set(fmt_pct(frac).as_str());
+        self.label.set(format::percent(frac).as_str());
     }
 
     /// Drag/click: set the volume to the cursor fraction (fill follows
@@ -334,7 +617,7 @@ impl VolumeHandle {
         let frac = (x_rel / w).clamp(0.0, 1.0);
         self.volume.set(frac);
         self.preview_px.set(x_rel.clamp(0.0, w));
-        self.label.set(fmt_pct(frac).as_str());
+        self.label.set(format::percent(frac).as_str());
     }
 
     /// Position the tooltip at a fraction along the bar (used by the wheel
@@ -342,17 +625,118 @@ impl VolumeHandle {
     pub fn label_at_frac(&self, frac: f32, lane_w: f32) {
         let frac = frac.clamp(0.0, 1.0);
         self.preview_px.set(frac * lane_w.max(1.0));
-        self.label.set(fmt_pct(frac).as_str());
+        self.label.set(format::percent(frac).as_str());
     }
 }
 
-/// Format a millisecond position as `M:SS` for the seek tooltip.
-fn fmt_ms(ms: u32) -> String {
-    let secs = ms / 1000;
-    format!("{}:{:02}", secs / 60, secs % 60)
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-/// Format a 0..=1 fraction as `NN%` for the volume tooltip.
-fn fmt_pct(frac: f32) -> String {
-    format!("{}%", (frac.clamp(0.0, 1.0) * 100.0).round() as u32)
+    /// Feed `(ms, action)` deliveries through the guard, polling for a
+    /// settled key before each one and once more at `end_ms` (as the frame
+    /// tick does); returns the actions dispatched, with their times.
+    fn run(keys: &[(u64, PlayerAction)], end_ms: u64) -> Vec<(u64, PlayerAction)> {
+        let m = PlayerModel::seed("", "", 0.0, 0, 0, 0.8);
+        let t0 = Instant::now();
+        let at = |ms: u64| t0 + Duration::from_millis(ms);
+        let mut out = Vec::new();
+        for &(ms, action) in keys.iter().chain([(end_ms, PlayerAction::Next)].iter()) {
+            if let Some(a) = m.settled_media_key(at(ms)) {
+                out.push((ms, a));
+            }
+            if ms == end_ms {
+                break;
+            }
+            if m.admit_media_key(action, at(ms)) == KeyVerdict::Admit {
+                out.push((ms, action));
+            }
+        }
+        out
+    }
+
+    const PP: PlayerAction = PlayerAction::PlayPause;
+
+    fn push(is_playing: bool, shuffle: bool) -> CurrentlyPlaying {
+        CurrentlyPlaying {
+            track_id: "spotify:track:a".into(),
+            name: "A".into(),
+            artist: String::new(),
+            album_image_url: None,
+            is_playing,
+            progress_ms: 0,
+            progress_anchor: Instant::now(),
+            duration_ms: 200_000,
+            shuffle,
+            repeat: RepeatMode::Off,
+            context_uri: None,
+        }
+    }
+
+    #[test]
+    fn pushes_that_differ_from_the_chrome_read_as_remote() {
+        let m = PlayerModel::seed("", "", 0.0, 0, 0, 0.8);
+        // The restored seed isn't live: the first push attributes nothing.
+        assert!(m.remote_intents(&push(true, true)).is_empty());
+        m.live.set(true);
+        m.is_playing.set(true);
+        // An echo of what the chrome already shows is nobody's action.
+        assert!(m.remote_intents(&push(true, false)).is_empty());
+        let actions: Vec<_> = m
+            .remote_intents(&push(false, true))
+            .into_iter()
+            .map(|i| (i.action, i.source))
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                (PlayerAction::Pause, ActionSource::Remote),
+                (PlayerAction::ToggleShuffle, ActionSource::Remote),
+            ]
+        );
+    }
+
+    #[test]
+    fn doubled_delivery_acts_once() {
+        assert_eq!(run(&[(0, PP), (20, PP)], 1_000), [(0, PP)]);
+    }
+
+    #[test]
+    fn deliberate_double_press_acts_twice() {
+        assert_eq!(run(&[(0, PP), (200, PP)], 1_000), [(0, PP), (1_000, PP)]);
+        // Settles as soon as the burst window has passed.
+        let m = PlayerModel::seed("", "", 0.0, 0, 0, 0.8);
+        let t0 = Instant::now();
+        let ms = |n| t0 + Duration::from_millis(n);
+        assert_eq!(m.admit_media_key(PP, ms(0)), KeyVerdict::Admit);
+        assert_eq!(m.admit_media_key(PP, ms(200)), KeyVerdict::Defer);
+        assert_eq!(m.settled_media_key(ms(230)), None);
+        assert_eq!(m.settled_media_key(ms(260)), Some(PP));
+        assert_eq!(m.settled_media_key(ms(300)), None);
+    }
+
+    #[test]
+    fn doubled_double_press_acts_twice() {
+        let keys = [(0, PP), (10, PP), (200, PP), (210, PP)];
+        assert_eq!(run(&keys, 1_000), [(0, PP), (1_000, PP)]);
+    }
+
+    #[test]
+    fn held_key_acts_once() {
+        // Press, the first auto-repeat after the typematic delay, then the
+        // ~30 Hz repeat stream until release.
+        let keys: Vec<_> = [0, 500]
+            .into_iter()
+            .chain((1..=15).map(|i| 500 + 33 * i))
+            .map(|ms| (ms, PP))
+            .collect();
+        assert_eq!(run(&keys, 2_000), [(0, PP)]);
+    }
+
+    #[test]
+    fn quiet_gap_or_another_key_admits_at_once() {
+        let keys = [(0, PP), (800, PP), (820, PlayerAction::Next)];
+        let want = [(0, PP), (800, PP), (820, PlayerAction::Next)];
+        assert_eq!(run(&keys, 2_000), want);
+    }
 }
@@ -0,0 +1,82 @@
+//! Low-power rendering slice.
+//!
+//! The ambient backdrop (two full-window covers + an 80 px frosted-glass
+//! blur) and the sticky playlist header's glass are the heaviest GPU work
+//! in the app. With "Reduce visual effects" on — or "on battery" on and
+//! the machine unplugged — `widgets::surface` builds tinted solid
+//! surfaces instead, `widgets::motion` lands the UI tweens at once, and
+//! the progress bar steps once a second rather than tweening every frame. [`PowerModel::tick`] re-evaluates once per frame
+//! (polling the power source every [`POLL_EVERY`]) and reports a flip so
+//! the host rebuilds.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use opal_gfx::Signal;
+
+use crate::power;
+
+/// How often to re-read the power source. Plugging in/out is rare and a
+/// few seconds' lag is invisible; the read itself is a few sysfs files.
+const POLL_EVERY: Duration = Duration::from_secs(10);
+
+pub struct PowerModel {
+    /// "Reduce visual effects" — always reduce (settings toggle).
+    pub reduce: Signal<bool>,
+    /// "Reduce on battery" — reduce while unplugged (settings toggle).
+    pub reduce_on_battery: Signal<bool>,
+    /// Last polled power source + when.
+    on_battery: Cell<bool>,
+    polled: Cell<Option<Instant>>,
+    /// The effective mode the current scene was built with.
+    active: Cell<bool>,
+}
+
+impl PowerModel {
+    pub fn new(reduce: bool, reduce_on_battery: bool) -> Self {
+        let model = Self {
+            reduce: Signal::new(reduce),
+            reduce_on_battery: Signal::new(reduce_on_battery),
+            on_battery: Cell::new(false),
+            polled: Cell::new(None),
+            active: Cell::new(false),
+        };
+        model.active.set(model.evaluate(Instant::now()));
+        model
+    }
+
+    /// Whether effects are currently reduced.
+    pub fn active(&self) -> bool {
+        self.active.get()
+    }
+
+    /// Per-frame re-evaluation. `true` when the effective mode flipped
+    /// (a toggle changed, or the machine was plugged in / unplugged) —
+    /// the caller rebuilds so the other surfaces are built.
+    pub fn tick(&self, now: Instant) -> bool {
+        let next = self.evaluate(now);
+        if next == self.active.replace(next) {
+            return false;
+        }
+        log::info!("visual effects {}", if next { "reduced" } else { "full" });
+        true
+    }
+
+    fn evaluate(&self, now: Instant) -> bool {
+        if self.reduce.get() {
+            return true;
+        }
+        if !self.reduce_on_battery.get() {
+            return false;
+        }
+        let due = self
+            .polled
+            .get()
+            .is_none_or(|at| now.saturating_duration_since(at) >= POLL_EVERY);
+        if due {
+            self.polled.set(Some(now));
+            self.on_battery.set(power::on_battery());
+        }
+        self.on_battery.get()
+    }
+}
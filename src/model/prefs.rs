@@ -1,8 +1,9 @@
 //! Persisted-preferences slice — the debounced save pipeline.
 //!
 //! Owns the live [`UserPreferences`] plus the signal-backed resizable
-//! panel widths and the debounce bookkeeping. Splitter drags and setting
-//! toggles call [`PrefsModel::mark_dirty`]; [`PrefsModel::tick`] (run
+//! panel widths and the debounce bookkeeping. Splitter drags
+//! ([`PrefsModel::resized`]) and setting toggles call
+//! [`PrefsModel::mark_dirty`]; [`PrefsModel::tick`] (run
 //! from the frame loop) writes once the prefs have been quiescent for
 //! [`PREFS_DEBOUNCE`], coalescing a drag burst (~60 events/sec) into a
 //! single disk write.
@@ -18,11 +19,22 @@ use opal_gfx::{Curve, Signal, Timeline};
 
 use crate::api::CurrentlyPlaying;
 use crate::prefs::{StoredPlayer, UserPreferences};
+use crate::widgets::motion;
 
 /// How long to wait after the last pref mutation before writing the
 /// file. Smooths out splitter-drag bursts into a single write per drag.
 const PREFS_DEBOUNCE: Duration = Duration::from_millis(500);
 
+/// Panel fold/restore tween (see [`PrefsModel::fold`]).
+const FOLD_DURATION: Duration = Duration::from_millis(220);
+
+/// A resizable side panel.
+#[derive(Debug, Clone, Copy)]
+pub enum Panel {
+    Sidebar,
+    NowPlaying,
+}
+
 pub struct PrefsModel {
     /// The serialized preferences. Mutated in place (cache dir, last
     /// player, panel widths) and written by the debounced save.
@@ -31,6 +43,11 @@ pub struct PrefsModel {
     /// (`width_px_bind`); snapshotted back into `data.panels` on save.
     pub sidebar_w: Signal<f32>,
     pub now_playing_w: Signal<f32>,
+    /// The user's width for a panel a layout breakpoint has folded (see
+    /// `ResponsiveModel`): saved in place of the live width, and tweened
+    /// back to when the window widens again.
+    held_sidebar: Cell<Option<f32>>,
+    held_now_playing: Cell<Option<f32>>,
     /// Earliest unsaved change since the last save. `None` = clean.
     dirty_since: Cell<Option<Instant>>,
     /// Throwaway signal anchoring a timeline tween that keeps the loop
@@ -46,6 +63,8 @@ impl PrefsModel {
             sidebar_w,
             now_playing_w,
             data: RefCell::new(prefs),
+            held_sidebar: Cell::new(None),
+            held_now_playing: Cell::new(None),
             dirty_since: Cell::new(None),
             save_anchor: Signal::new(0.0),
         }
@@ -83,8 +102,9 @@ impl PrefsModel {
         self.snapshot_player(player);
         {
             let mut prefs = self.data.borrow_mut();
-            prefs.panels.sidebar_w = self.sidebar_w.get();
-            prefs.panels.now_playing_w = self.now_playing_w.get();
+            prefs.panels.sidebar_w = self.held_sidebar.get().unwrap_or(self.sidebar_w.get());
+            prefs.panels.now_playing_w =
+                self.held_now_playing.get().unwrap_or(self.now_playing_w.get());
             prefs.show_canvas = show_canvas;
         }
         self.data.borrow().save()
@@ -129,6 +149,8 @@ impl PrefsModel {
         let defaults = UserPreferences::default();
         self.sidebar_w.set(defaults.panels.sidebar_w);
         self.now_playing_w.set(defaults.panels.now_playing_w);
+        self.held_sidebar.set(None);
+        self.held_now_playing.set(None);
         *self.data.borrow_mut() = defaults;
         self.dirty_since.set(None);
         match self.data.borrow().save() {
@@ -137,6 +159,41 @@ impl PrefsModel {
         }
     }
 
+    fn panel(&self, panel: Panel) -> (&Signal<f32>, &Cell<Option<f32>>) {
+        match panel {
+            Panel::Sidebar => (&self.sidebar_w, &self.held_sidebar),
+            Panel::NowPlaying => (&self.now_playing_w, &self.held_now_playing),
+        }
+    }
+
+    /// Tween `panel` down to `to` px for a narrow window, holding on to
+    /// the user's width. No-op when it's already that narrow (closed by
+    /// hand, or already folded).
+    pub fn fold(&self, panel: Panel, to: f32, tl: &mut Timeline, now: Instant) {
+        let (width, held) = self.panel(panel);
+        if width.get() <= to || held.get().is_some() {
+            return;
+        }
+        held.set(Some(width.get()));
+        tl.animate(width, to, Curve::EaseInOut, motion::dur(FOLD_DURATION), now);
+    }
+
+    /// A splitter drag on `panel`: the user chose a width, so a width held
+    /// by a fold is stale — drop it, so the save keeps the dragged width
+    /// and a later widen doesn't snap back over it. Marks prefs dirty.
+    pub fn resized(&self, panel: Panel, now: Instant) {
+        self.panel(panel).1.set(None);
+        self.mark_dirty(now);
+    }
+
+    /// Undo a [`Self::fold`]: tween `panel` back to the held width.
+    pub fn unfold(&self, panel: Panel, tl: &mut Timeline, now: Instant) {
+        let (width, held) = self.panel(panel);
+        if let Some(w) = held.take() {
+            tl.animate(width, w, Curve::EaseInOut, motion::dur(FOLD_DURATION), now);
+        }
+    }
+
     /// Force a final flush on app close — picks up a mouse-up we might
     /// have missed (drag released outside the window) and persists the
     /// live snapshot so the next launch re-hydrates immediately.
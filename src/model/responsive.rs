@@ -0,0 +1,125 @@
+//! Responsive layout slice: folds the side panels away as the window
+//! narrows, so the centre pane keeps a usable width.
+//!
+//! Two breakpoints on the window's logical width: below
+//! [`HIDE_NOW_PLAYING_BELOW`] the now-playing panel folds shut, and below
+//! [`COLLAPSE_SIDEBAR_BELOW`] the library sidebar also drops to its icon
+//! rail. Each fold tweens the panel's width signal, so it animates like a
+//! splitter drag with no rebuild; widening past the breakpoint again
+//! brings back the width the user had. The user's widths stay what's
+//! saved to prefs throughout (see [`PrefsModel::fold`]).
+
+use std::cell::Cell;
+use std::time::Instant;
+
+use opal_gfx::Timeline;
+
+use crate::model::PrefsModel;
+use crate::model::prefs::Panel;
+use crate::widgets::tokens as t;
+
+/// Window width (logical px) under which the now-playing panel folds.
+pub const HIDE_NOW_PLAYING_BELOW: f32 = 900.0;
+/// Window width (logical px) under which the sidebar collapses to icons.
+pub const COLLAPSE_SIDEBAR_BELOW: f32 = 700.0;
+
+/// Which panels the current window width leaves open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Breakpoint {
+    /// Everything at the user's widths.
+    #[default]
+    Wide,
+    /// Now-playing folded.
+    Medium,
+    /// Now-playing folded + sidebar on its icon rail.
+    Narrow,
+}
+
+impl Breakpoint {
+    pub fn for_width(w: f32) -> Self {
+        if w < COLLAPSE_SIDEBAR_BELOW {
+            Breakpoint::Narrow
+        } else if w < HIDE_NOW_PLAYING_BELOW {
+            Breakpoint::Medium
+        } else {
+            Breakpoint::Wide
+        }
+    }
+
+    fn folds_now_playing(self) -> bool {
+        self != Breakpoint::Wide
+    }
+
+    fn folds_sidebar(self) -> bool {
+        self == Breakpoint::Narrow
+    }
+}
+
+pub struct ResponsiveModel {
+    /// Breakpoint last applied. Starts `Wide` (nothing folded), so the
+    /// first tick after launch folds whatever the window size calls for.
+    current: Cell<Breakpoint>,
+}
+
+impl ResponsiveModel {
+    pub fn new() -> Self {
+        Self {
+            current: Cell::new(Breakpoint::Wide),
+        }
+    }
+
+    /// Per-frame with the window's logical width: on a breakpoint change,
+    /// fold or restore the panels it affects.
+    pub fn tick(&self, width: f32, prefs: &PrefsModel, tl: &mut Timeline, now: Instant) {
+        let next = Breakpoint::for_width(width);
+        let prev = self.current.replace(next);
+        if next == prev {
+            return;
+        }
+        log::debug!("layout breakpoint {prev:?} -> {next:?} at {width:.0}px");
+        for (panel, was, is, folded) in [
+            (
+                Panel::NowPlaying,
+                prev.folds_now_playing(),
+                next.folds_now_playing(),
+                t::SP_0,
+            ),
+            (
+                Panel::Sidebar,
+                prev.folds_sidebar(),
+                next.folds_sidebar(),
+                t::SIDEBAR_COLLAPSED,
+            ),
+        ] {
+            match (was, is) {
+                (false, true) => prefs.fold(panel, folded, tl, now),
+                (true, false) => prefs.unfold(panel, tl, now),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Default for ResponsiveModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakpoints_fold_panels_from_the_right() {
+        assert_eq!(Breakpoint::for_width(1280.0), Breakpoint::Wide);
+        assert_eq!(
+            Breakpoint::for_width(HIDE_NOW_PLAYING_BELOW),
+            Breakpoint::Wide
+        );
+        assert_eq!(Breakpoint::for_width(820.0), Breakpoint::Medium);
+        assert_eq!(Breakpoint::for_width(640.0), Breakpoint::Narrow);
+        assert!(Breakpoint::Medium.folds_now_playing() && !Breakpoint::Medium.folds_sidebar());
+        assert!(Breakpoint::Narrow.folds_now_playing() && Breakpoint::Narrow.folds_sidebar());
+    }
+}
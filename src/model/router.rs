@@ -14,6 +14,7 @@ use std::time::{Duration, Instant};
 use opal_gfx::{Curve, Signal, Timeline};
 
 use crate::views::{MainNav, View};
+use crate::widgets::motion;
 
 /// Centre-pane content transition duration on nav change.
 const MAIN_NAV_DURATION: Duration = Duration::from_millis(260);
@@ -66,7 +67,8 @@ impl RouterModel {
         }
         self.view.set(view);
         self.view_t.set(0.0);
-        tl.animate(&self.view_t, 1.0, NAV_CURVE, MAIN_NAV_DURATION, now);
+        let d = motion::dur(MAIN_NAV_DURATION);
+        tl.animate(&self.view_t, 1.0, NAV_CURVE, d, now);
     }
 
     /// Whether the centre pane is showing the detail page (playlist or album)
@@ -78,7 +80,9 @@ impl RouterModel {
             MainNav::Home
             | MainNav::Artist { .. }
             | MainNav::ShowAll { .. }
-            | MainNav::Queue => false,
+            | MainNav::Queue
+            | MainNav::Lyrics
+            | MainNav::Search => false,
         }
     }
 
@@ -95,7 +99,8 @@ impl RouterModel {
         // New page starts scrolled to top → header fully expanded.
         self.detail_collapse.set(0.0);
         self.main_t.set(0.0);
-        tl.animate(&self.main_t, 1.0, NAV_CURVE, MAIN_NAV_DURATION, now);
+        let d = motion::dur(MAIN_NAV_DURATION);
+        tl.animate(&self.main_t, 1.0, NAV_CURVE, d, now);
     }
 }
 
@@ -0,0 +1,235 @@
+//! Search slice: the top-bar field's text, its debounce, and the results
+//! for the latest dispatched query.
+//!
+//! Typing only records the text + edit time; the frame tick asks
+//! [`SearchModel::due`] and dispatches once the field has been still for
+//! [`DEBOUNCE`], so a burst of keystrokes costs one request. Responses
+//! carry their query, and one that no longer matches the field is dropped
+//! — a slow earlier query can't overwrite a newer one's results. A failed
+//! request marks the query failed, so the page says so and the same text
+//! goes out again on the next edit or Enter.
+//!
+//! Up/Down in the field move a highlight through the shown songs (see
+//! [`SearchModel::step`]); Enter plays the highlighted one, or the top
+//! song when nothing is highlighted.
+
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+use crate::api::SearchResults;
+
+/// Quiet time after the last keystroke before the query goes out.
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+#[derive(Default)]
+pub struct SearchModel {
+    /// Current field text, as typed.
+    text: RefCell<String>,
+    /// Last edit not yet dispatched (debounce deadline anchor).
+    edited_at: Cell<Option<Instant>>,
+    /// Last query sent to the worker (trimmed).
+    sent: RefCell<String>,
+    /// Results for `sent`; `None` while in flight.
+    pub results: RefCell<Option<SearchResults>>,
+    /// The request for `sent` failed; it no longer counts as sent.
+    failed: Cell<bool>,
+    /// Keyboard-highlighted song (index among the playable tracks of
+    /// `results`). `None` leaves the caret in the field.
+    selected: Cell<Option<usize>>,
+}
+
+impl SearchModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The query the page is showing results for (trimmed).
+    pub fn query(&self) -> String {
+        self.sent.borrow().clone()
+    }
+
+    /// The shown query's request failed (no results coming).
+    pub fn failed(&self) -> bool {
+        self.failed.get()
+    }
+
+    /// Keyboard-highlighted song, as an index among the playable tracks.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected.get()
+    }
+
+    /// Move the highlight one song down (`down`) or up. Down from the
+    /// field lands on the first song and stops at the last; up from the
+    /// first goes back to the field. `false` when nothing changed (no
+    /// results yet, or already at an end) — the key is then the field's.
+    pub fn step(&self, down: bool) -> bool {
+        let songs = self
+            .results
+            .borrow()
+            .as_ref()
+            .map_or(0, |r| r.tracks.iter().filter(|t| t.playable).count());
+        let cur = self.selected.get();
+        let next = match (cur, down) {
+            _ if songs == 0 => None,
+            (None, true) => Some(0),
+            (None, false) => None,
+            (Some(i), true) => Some((i + 1).min(songs - 1)),
+            (Some(i), false) => i.checked_sub(1),
+        };
+        self.selected.set(next);
+        next != cur
+    }
+
+    /// Current field text — seeds the field on rebuild.
+    pub fn text(&self) -> String {
+        self.text.borrow().clone()
+    }
+
+    /// Record a field edit at `now`; dispatch waits for [`DEBOUNCE`].
+    pub fn edit(&self, text: &str, now: Instant) {
+        *self.text.borrow_mut() = text.to_string();
+        self.edited_at.set(Some(now));
+        self.selected.set(None);
+    }
+
+    /// Skip the remaining debounce (Enter in the field). After a failure
+    /// this re-sends the unchanged query.
+    pub fn flush(&self) {
+        let pending = self.edited_at.get();
+        if let Some(at) = pending.or_else(|| self.failed.get().then(Instant::now)) {
+            self.edited_at.set(Some(at.checked_sub(DEBOUNCE).unwrap_or(at)));
+        }
+    }
+
+    /// Per-frame check. `Some(query)` once the field has been quiet for
+    /// [`DEBOUNCE`] and its trimmed text differs from the last dispatch
+    /// (or that dispatch failed) — the caller sends it (an empty query
+    /// just clears the results).
+    pub fn due(&self, now: Instant) -> Option<String> {
+        let at = self.edited_at.get()?;
+        if now.saturating_duration_since(at) < DEBOUNCE {
+            return None;
+        }
+        self.edited_at.set(None);
+        let query = self.text.borrow().trim().to_string();
+        if query == *self.sent.borrow() && !self.failed.get() {
+            return None;
+        }
+        self.failed.set(false);
+        *self.sent.borrow_mut() = query.clone();
+        *self.results.borrow_mut() = None;
+        self.selected.set(None);
+        Some(query)
+    }
+
+    /// Store a worker response. `false` (and dropped) when the field has
+    /// moved on to another query since it was sent.
+    pub fn apply(&self, query: &str, results: SearchResults) -> bool {
+        if query != *self.sent.borrow() {
+            return false;
+        }
+        *self.results.borrow_mut() = Some(results);
+        true
+    }
+
+    /// The request for `query` failed. `false` (ignored) when the field
+    /// has moved on since it was sent.
+    pub fn fail(&self, query: &str) -> bool {
+        if query != *self.sent.borrow() {
+            return false;
+        }
+        self.failed.set(true);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatches_once_after_the_field_goes_quiet() {
+        let m = SearchModel::new();
+        let t0 = Instant::now();
+        m.edit("da", t0);
+        m.edit("daft ", t0 + Duration::from_millis(100));
+        assert_eq!(m.due(t0 + Duration::from_millis(300)), None);
+        assert_eq!(m.due(t0 + Duration::from_millis(400)).as_deref(), Some("daft"));
+        assert_eq!(m.due(t0 + Duration::from_millis(900)), None);
+        // Whitespace-only change: same query, nothing new to send.
+        m.edit("daft", t0 + Duration::from_secs(1));
+        assert_eq!(m.due(t0 + Duration::from_secs(2)), None);
+    }
+
+    #[test]
+    fn stale_responses_are_dropped() {
+        let m = SearchModel::new();
+        let t0 = Instant::now();
+        m.edit("abba", t0);
+        assert!(m.due(t0 + DEBOUNCE).is_some());
+        m.edit("abbey road", t0 + DEBOUNCE);
+        m.flush();
+        assert!(m.due(t0 + DEBOUNCE).is_some());
+        assert!(!m.apply("abba", SearchResults::default()));
+        assert!(m.results.borrow().is_none());
+        assert!(m.apply("abbey road", SearchResults::default()));
+        assert!(m.results.borrow().is_some());
+    }
+
+    #[test]
+    fn a_failed_query_can_be_sent_again() {
+        let m = SearchModel::new();
+        let t0 = Instant::now();
+        m.edit("abba", t0);
+        assert!(m.due(t0 + DEBOUNCE).is_some());
+        assert!(!m.fail("abb"));
+        assert!(m.fail("abba"));
+        assert!(m.failed());
+        // Retyping the same text retries it.
+        m.edit("abba", t0 + DEBOUNCE);
+        assert_eq!(m.due(t0 + DEBOUNCE * 2).as_deref(), Some("abba"));
+        assert!(!m.failed());
+    }
+
+    #[test]
+    fn arrows_walk_the_songs_and_up_returns_to_the_field() {
+        use crate::api::PlaylistTrack;
+        let m = SearchModel::new();
+        // Nothing to walk before results land.
+        assert!(!m.step(true));
+        let song = |playable| PlaylistTrack {
+            id: String::new(),
+            uri: String::new(),
+            name: String::new(),
+            artist: String::new(),
+            album: String::new(),
+            album_image_url: None,
+            duration_ms: 0,
+            artists: Vec::new(),
+            album_id: String::new(),
+            artist_id: String::new(),
+            playable,
+        };
+        m.edit("abba", Instant::now());
+        assert!(m.due(Instant::now() + DEBOUNCE).is_some());
+        let results = SearchResults {
+            tracks: vec![song(true), song(false), song(true)],
+            ..Default::default()
+        };
+        assert!(m.apply("abba", results));
+        assert!(m.step(true));
+        assert_eq!(m.selected(), Some(0));
+        assert!(m.step(true));
+        // Unplayable rows aren't shown, so two songs: stop at the last.
+        assert!(!m.step(true));
+        assert_eq!(m.selected(), Some(1));
+        assert!(m.step(false));
+        assert!(m.step(false));
+        assert_eq!(m.selected(), None);
+        assert!(!m.step(false));
+        // Typing drops the highlight.
+        m.step(true);
+        m.edit("abba gold", Instant::now());
+        assert_eq!(m.selected(), None);
+    }
+}
@@ -1,10 +1,12 @@
 //! Settings-modal slice.
 //!
 //! Owns the modal [`Overlay`] (self-contained scrim/fade/input-blocking),
-//! the last-measured on-disk cache usage shown in the storage bar, and
-//! the cross-thread handoff slot for the (blocking) folder-picker dialog.
+//! the last-measured on-disk cache usage shown in the storage bar, the
+//! audio outputs offered by the device picker, the locales offered by the
+//! language picker, and the cross-thread handoff slot for the (blocking)
+//! folder-picker dialog.
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
@@ -23,17 +25,42 @@ pub struct SettingsModel {
     /// switch reactively. The pref is read at session start (applies on
     /// next launch), so this only mirrors + persists the choice.
     pub normalize: Signal<bool>,
+    /// "Warm up playlists" toggle — seeded from prefs; read at the next
+    /// launch's auth, so like `normalize` it only mirrors + persists.
+    pub warmup: Signal<bool>,
+    /// Streaming-quality dropdown expanded. Closed again on every open of
+    /// the modal.
+    pub quality_open: Cell<bool>,
+    /// Output device names last enumerated from the audio host, listed
+    /// under "System default" in the device picker. Re-listed by the
+    /// worker on every open (`WorkerResponse::OutputDevices`).
+    pub output_devices: RefCell<Vec<String>>,
+    /// Output-device dropdown expanded. Closed on every open, like
+    /// `quality_open`.
+    pub device_open: Cell<bool>,
+    /// Locales offered by the language picker as `(code, name)`, English
+    /// first. Re-listed from the locales folder on every open.
+    pub locales: RefCell<Vec<(String, String)>>,
+    /// Language dropdown expanded. Closed on every open, like
+    /// `quality_open`.
+    pub locale_open: Cell<bool>,
     /// Folder picked by the off-thread (blocking) cache-relocation dialog,
     /// awaiting pickup on the UI thread in the frame loop.
     pub pending_cache_dir: Arc<Mutex<Option<PathBuf>>>,
 }
 
 impl SettingsModel {
-    pub fn new(normalize: bool) -> Self {
+    pub fn new(normalize: bool, warmup: bool) -> Self {
         Self {
             overlay: Overlay::new(),
             cache_usage: Cell::new(disk_cache::CacheUsage::default()),
             normalize: Signal::new(normalize),
+            warmup: Signal::new(warmup),
+            quality_open: Cell::new(false),
+            output_devices: RefCell::new(Vec::new()),
+            device_open: Cell::new(false),
+            locales: RefCell::new(Vec::new()),
+            locale_open: Cell::new(false),
             pending_cache_dir: Arc::new(Mutex::new(None)),
         }
     }
@@ -44,6 +71,11 @@ impl SettingsModel {
         self.cache_usage.set(disk_cache::usage());
     }
 
+    /// Re-list the locale bundles for the language picker (settings open).
+    pub fn refresh_locales(&self) {
+        *self.locales.borrow_mut() = crate::i18n::locales();
+    }
+
     /// Wipe every cached file (art, Canvas videos, API JSON) and refresh
     /// the usage bar. Returns bytes freed. Fast — the cache is capped.
     pub fn clear_cache(&self) -> u64 {
@@ -78,6 +110,6 @@ impl SettingsModel {
 
 impl Default for SettingsModel {
     fn default() -> Self {
-        Self::new(true)
+        Self::new(true, true)
     }
 }
@@ -0,0 +1,71 @@
+//! Suspend/resume detection.
+//!
+//! `Instant` is monotonic and on Linux/macOS it **stops** while the machine
+//! sleeps, so after a lid-close the proactive token refresh (keyed off an
+//! `Instant` deadline) still thinks there's plenty of time — and the first
+//! user action 401s. The wall clock keeps running across sleep, so each
+//! frame tick compares how far both clocks moved since the last one; wall
+//! time outrunning monotonic time by more than [`RESUME_GAP`] means the
+//! process was suspended. (On Windows `Instant` keeps counting through
+//! sleep, so the ordinary refresh deadline already fires there.)
+
+use std::cell::Cell;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Wall-clock drift beyond monotonic time that counts as a suspend. Well
+/// clear of NTP slews; well under a token's lifetime.
+const RESUME_GAP: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+pub struct SuspendModel {
+    /// Both clocks as of the previous tick.
+    last: Cell<Option<(Instant, SystemTime)>>,
+    /// A resume was detected and the post-resume resync (player state +
+    /// devices) waits on the fresh token. Consumed by the refresh response.
+    resync_pending: Cell<bool>,
+}
+
+impl SuspendModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Per-frame check. Returns how long the machine slept when this tick
+    /// is the first one after a resume (and arms the resync), else `None`.
+    pub fn tick(&self, now: Instant) -> Option<Duration> {
+        let wall = SystemTime::now();
+        let prev = self.last.replace(Some((now, wall)));
+        let (then, then_wall) = prev?;
+        let slept = slept(now - then, wall.duration_since(then_wall).ok()?)?;
+        self.resync_pending.set(true);
+        Some(slept)
+    }
+
+    /// Whether a post-resume resync is owed. Clears on read.
+    pub fn take_resync(&self) -> bool {
+        self.resync_pending.replace(false)
+    }
+}
+
+/// Sleep duration implied by the two clock deltas, if past [`RESUME_GAP`].
+fn slept(mono: Duration, wall: Duration) -> Option<Duration> {
+    wall.checked_sub(mono).filter(|gap| *gap > RESUME_GAP)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_wall_clock_drift_past_the_gap_counts_as_sleep() {
+        let s = Duration::from_secs;
+        // An idle loop parked for ten minutes: both clocks moved together.
+        assert_eq!(slept(s(600), s(600)), None);
+        // Small skew (NTP) is ignored.
+        assert_eq!(slept(s(1), s(10)), None);
+        // Lid closed for an hour: monotonic barely moved.
+        assert_eq!(slept(s(2), s(3_602)), Some(s(3_600)));
+        // Wall clock stepped backwards — never a resume.
+        assert_eq!(slept(s(5), s(0)), None);
+    }
+}
@@ -0,0 +1,214 @@
+//! Toast slice: transient notifications stacked in the window corner.
+//!
+//! Anything with an `&AppState` posts one with [`ToastModel::show`]; the
+//! frame tick ([`ToastModel::tick`]) then runs each toast's life — slide
+//! in, count down, slide out, drop — and reports when the stack changed so
+//! the host rebuilds. Hovering a toast pauses its countdown (the view
+//! binds [`Toast::hovered`]), and the close button ends it early.
+
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+use opal_gfx::{Curve, Signal, Timeline};
+
+use crate::widgets::motion;
+
+/// Toasts on screen at once; posting another retires the oldest.
+const MAX_VISIBLE: usize = 3;
+/// Slide/fade in and out.
+const ENTER: Duration = Duration::from_millis(220);
+const EXIT: Duration = Duration::from_millis(180);
+/// Default time on screen (excluding hover).
+const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+/// Errors stay up longer — they usually mean something the user asked for
+/// didn't happen, and they're worth reading.
+pub const ERROR_DURATION: Duration = Duration::from_secs(8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Error,
+}
+
+#[derive(Clone, Copy)]
+enum Phase {
+    /// Posted; the next tick starts the entry tween.
+    Posted,
+    /// On screen until `deadline`; `paused_at` while hovered.
+    Shown {
+        deadline: Instant,
+        paused_at: Option<Instant>,
+    },
+    /// Exit tween running; removed at `until`.
+    Leaving { until: Instant },
+}
+
+pub struct Toast {
+    pub id: u64,
+    pub message: String,
+    pub kind: ToastKind,
+    /// Entry/exit progress 0..=1 — drives the slide + fade.
+    pub t: Signal<f32>,
+    /// Cursor over the toast (pauses the countdown).
+    pub hovered: Signal<bool>,
+    duration: Duration,
+    /// Close clicked or pushed out by a newer toast.
+    retired: Cell<bool>,
+    phase: Cell<Phase>,
+}
+
+pub struct ToastModel {
+    /// Oldest first.
+    pub toasts: RefCell<Vec<Toast>>,
+    next_id: Cell<u64>,
+    /// Stack changed since the last tick (a post or a removal).
+    dirty: Cell<bool>,
+    /// Throwaway tween keeping the frame loop alive up to the nearest
+    /// deadline (see `PrefsModel::tick`).
+    anchor: Signal<f32>,
+}
+
+impl ToastModel {
+    pub fn new() -> Self {
+        Self {
+            toasts: RefCell::new(Vec::new()),
+            next_id: Cell::new(0),
+            dirty: Cell::new(false),
+            anchor: Signal::new(0.0),
+        }
+    }
+
+    /// Post a toast for [`DEFAULT_DURATION`]; [`show_for`](Self::show_for)
+    /// picks the time.
+    pub fn show(&self, message: impl Into<String>, kind: ToastKind) {
+        self.show_for(message, kind, DEFAULT_DURATION);
+    }
+
+    /// Post a toast that stays `duration` (plus any time hovered).
+    pub fn show_for(&self, message: impl Into<String>, kind: ToastKind, duration: Duration) {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        let mut toasts = self.toasts.borrow_mut();
+        toasts.push(Toast {
+            id,
+            message: message.into(),
+            kind,
+            t: Signal::new(0.0),
+            hovered: Signal::new(false),
+            duration,
+            retired: Cell::new(false),
+            phase: Cell::new(Phase::Posted),
+        });
+        let live = toasts.iter().filter(|t| !t.retired.get()).count();
+        let excess = live.saturating_sub(MAX_VISIBLE);
+        for old in toasts.iter().filter(|t| !t.retired.get()).take(excess) {
+            old.retired.set(true);
+        }
+        self.dirty.set(true);
+    }
+
+    /// Close button: end toast `id` now (it still animates out).
+    pub fn dismiss(&self, id: u64) {
+        if let Some(t) = self.toasts.borrow().iter().find(|t| t.id == id) {
+            t.retired.set(true);
+        }
+    }
+
+    /// Per-frame lifecycle step. `true` when a toast was added or removed
+    /// — the caller rebuilds so the stack re-lays out.
+    pub fn tick(&self, tl: &mut Timeline, now: Instant) -> bool {
+        let mut next_deadline: Option<Instant> = None;
+        for toast in self.toasts.borrow().iter() {
+            let phase = match toast.phase.get() {
+                Phase::Posted => {
+                    let enter = motion::dur(ENTER);
+                    tl.animate(&toast.t, 1.0, Curve::EaseOut, enter, now);
+                    Phase::Shown {
+                        deadline: now + enter + toast.duration,
+                        paused_at: None,
+                    }
+                }
+                Phase::Shown {
+                    mut deadline,
+                    mut paused_at,
+                } => {
+                    // Hover edges arrive as events, so `now` here is when
+                    // the cursor entered/left; the paused span is added back.
+                    match (toast.hovered.get(), paused_at) {
+                        (true, None) => paused_at = Some(now),
+                        (false, Some(at)) => {
+                            deadline += now.saturating_duration_since(at);
+                            paused_at = None;
+                        }
+                        _ => {}
+                    }
+                    if toast.retired.get() || (paused_at.is_none() && now >= deadline) {
+                        let exit = motion::dur(EXIT);
+                        tl.animate(&toast.t, 0.0, Curve::EaseIn, exit, now);
+                        Phase::Leaving { until: now + exit }
+                    } else {
+                        if paused_at.is_none() {
+                            next_deadline =
+                                Some(next_deadline.map_or(deadline, |d| d.min(deadline)));
+                        }
+                        Phase::Shown {
+                            deadline,
+                            paused_at,
+                        }
+                    }
+                }
+                leaving => leaving,
+            };
+            toast.phase.set(phase);
+        }
+        let before = self.toasts.borrow().len();
+        self.toasts
+            .borrow_mut()
+            .retain(|t| !matches!(t.phase.get(), Phase::Leaving { until } if now >= until));
+        let removed = self.toasts.borrow().len() != before;
+        if let Some(deadline) = next_deadline {
+            let remaining = deadline.saturating_duration_since(now) + Duration::from_millis(50);
+            self.anchor.set(0.0);
+            tl.animate(&self.anchor, 1.0, Curve::Linear, remaining, now);
+        }
+        self.dirty.take() || removed
+    }
+}
+
+impl Default for ToastModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posting_past_the_cap_retires_the_oldest() {
+        let m = ToastModel::new();
+        for msg in ["a", "b", "c", "d"] {
+            m.show(msg, ToastKind::Info);
+        }
+        m.dismiss(2);
+        m.show("e", ToastKind::Error);
+        let retired: Vec<_> = m
+            .toasts
+            .borrow()
+            .iter()
+            .map(|t| (t.message.clone(), t.retired.get()))
+            .collect();
+        // "a" made room for "d"; "c" was closed, so "e" fits without
+        // pushing "b" out.
+        let want = [
+            ("a", true),
+            ("b", false),
+            ("c", true),
+            ("d", false),
+            ("e", false),
+        ];
+        assert_eq!(retired, want.map(|(m, r)| (m.to_string(), r)));
+    }
+}
@@ -0,0 +1,172 @@
+//! Power-source detection for the low-power rendering mode (see
+//! [`crate::model::PowerModel`]).
+//!
+//! Each platform asks its cheapest source, all fine for the UI thread at
+//! the model's poll rate:
+//! - Linux reads `/sys/class/power_supply` directly — a handful of tiny
+//!   sysfs files.
+//! - Windows calls `GetSystemPowerStatus` (kernel32).
+//! - macOS asks IOKit which power source is providing power.
+//!
+//! Anywhere else the answer is always "on AC", so only the explicit
+//! toggle reduces effects.
+
+/// Whether the machine is currently running on battery.
+pub fn on_battery() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        linux::on_battery()
+    }
+    #[cfg(windows)]
+    {
+        windows::on_battery()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        macos::on_battery()
+    }
+    #[cfg(not(any(target_os = "linux", windows, target_os = "macos")))]
+    {
+        false
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs;
+    use std::path::Path;
+
+    const ROOT: &str = "/sys/class/power_supply";
+
+    pub fn on_battery() -> bool {
+        let Ok(entries) = fs::read_dir(ROOT) else {
+            return false;
+        };
+        let supplies: Vec<(String, String)> = entries
+            .flatten()
+            .map(|e| {
+                let dir = e.path();
+                (read(&dir, "type"), state(&dir))
+            })
+            .collect();
+        super::discharging(supplies.iter().map(|(k, s)| (k.as_str(), s.as_str())))
+    }
+
+    /// `online` for a mains adapter, `status` for a battery.
+    fn state(dir: &Path) -> String {
+        match read(dir, "type").as_str() {
+            "Mains" => read(dir, "online"),
+            _ => read(dir, "status"),
+        }
+    }
+
+    fn read(dir: &Path, file: &str) -> String {
+        fs::read_to_string(dir.join(file))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    /// `SYSTEM_POWER_STATUS` from `winbase.h`; the OS fills every field,
+    /// only the AC line is read.
+    #[repr(C)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    struct SystemPowerStatus {
+        ac_line_status: u8,
+        battery_flag: u8,
+        battery_life_percent: u8,
+        system_status_flag: u8,
+        battery_life_time: u32,
+        battery_full_life_time: u32,
+    }
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetSystemPowerStatus(status: *mut SystemPowerStatus) -> i32;
+    }
+
+    /// `ACLineStatus` is 0 offline, 1 online, 255 unknown; only a definite
+    /// "offline" counts, so desktops and odd firmware read as AC.
+    pub fn on_battery() -> bool {
+        let mut status = SystemPowerStatus::default();
+        // SAFETY: `status` is a valid, writable `SYSTEM_POWER_STATUS`.
+        let ok = unsafe { GetSystemPowerStatus(&mut status) } != 0;
+        ok && status.ac_line_status == 0
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use std::ffi::{c_char, c_void};
+
+    type CFTypeRef = *const c_void;
+    type CFStringRef = *const c_void;
+
+    const UTF8: u32 = 0x0800_0100; // kCFStringEncodingUTF8
+
+    #[link(name = "IOKit", kind = "framework")]
+    unsafe extern "C" {
+        fn IOPSCopyPowerSourcesInfo() -> CFTypeRef;
+        fn IOPSGetProvidingPowerSourceType(snapshot: CFTypeRef) -> CFStringRef;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    unsafe extern "C" {
+        fn CFRelease(cf: CFTypeRef);
+        fn CFStringGetCString(s: CFStringRef, buf: *mut c_char, len: isize, enc: u32) -> u8;
+    }
+
+    /// The providing source is `"AC Power"`, `"Battery Power"` or `"UPS
+    /// Power"` (`kIOPM*Key`); no snapshot (no power-source support) reads
+    /// as AC.
+    pub fn on_battery() -> bool {
+        // SAFETY: the snapshot is a Create/Copy-rule object released below;
+        // the source-type string is owned by it (Get rule) and only read
+        // while it lives.
+        unsafe {
+            let snapshot = IOPSCopyPowerSourcesInfo();
+            if snapshot.is_null() {
+                return false;
+            }
+            let kind = IOPSGetProvidingPowerSourceType(snapshot);
+            let mut buf = [0 as c_char; 32];
+            let read = !kind.is_null()
+                && CFStringGetCString(kind, buf.as_mut_ptr(), buf.len() as isize, UTF8) != 0;
+            CFRelease(snapshot);
+            read && std::ffi::CStr::from_ptr(buf.as_ptr()).to_bytes() == b"Battery Power"
+        }
+    }
+}
+
+/// Decide from `(type, state)` pairs: any mains adapter online means AC;
+/// otherwise a discharging battery means battery. Desktops (no battery)
+/// and unknown layouts read as AC.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn discharging<'a>(mut supplies: impl Iterator<Item = (&'a str, &'a str)> + Clone) -> bool {
+    if supplies.clone().any(|(kind, s)| kind == "Mains" && s == "1") {
+        return false;
+    }
+    supplies.any(|(kind, s)| kind == "Battery" && s == "Discharging")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mains_online_wins_over_battery_status() {
+        let laptop_unplugged = [("Mains", "0"), ("Battery", "Discharging")];
+        assert!(discharging(laptop_unplugged.into_iter()));
+        let laptop_charging = [("Mains", "1"), ("Battery", "Charging")];
+        assert!(!discharging(laptop_charging.into_iter()));
+        // Some firmware reports the battery stale for a moment after plug-in.
+        let stale = [("Mains", "1"), ("Battery", "Discharging")];
+        assert!(!discharging(stale.into_iter()));
+        // Desktop: no supplies, or only a UPS/USB entry.
+        assert!(!discharging([].into_iter()));
+        assert!(!discharging([("USB", "")].into_iter()));
+    }
+}
@@ -1,31 +1,48 @@
 //! User preferences — persisted across sessions as JSON in the OS
 //! config directory.
 //!
-//! Schema is versioned (`version` field) so future migrations can detect
-//! and adapt older files. Every field carries `#[serde(default)]` so
+//! Schema is versioned (`version` field): an older file is run through
+//! the [`MIGRATIONS`] chain (one JSON-level step per version bump) before
+//! it's deserialized, so a rename/reshape never silently drops the user's
+//! settings. Every field carries `#[serde(default)]` so
 //! adding a new field is forward-compatible: an old preferences file
 //! missing the field deserializes cleanly, the new field picks up its
 //! Default value, and the next save writes the upgraded shape.
 //!
+//! Unknown top-level keys (from a newer build) are kept and written back
+//! on save; unknown keys *inside* a section are still dropped.
+//!
 //! Loading is fail-soft: any error (missing file, malformed JSON,
 //! permission denied) yields [`UserPreferences::default`]. Saving is
 //! best-effort — a write failure is logged but does not propagate.
 //!
-//! Scope today: panel sizes, window geometry, audio prefs. Extend by
-//! adding a field-with-`#[serde(default)]` to [`UserPreferences`] or
-//! one of its child structs; no migration needed for additive changes.
+//! Scope today: panel sizes, window geometry, audio prefs, warm-up list.
+//! Extend by adding a field-with-`#[serde(default)]` to
+//! [`UserPreferences`] or one of its child structs; no migration needed
+//! for additive changes.
+//! For an incompatible change: bump [`SCHEMA_VERSION`] and append the
+//! step that rewrites the previous shape to [`MIGRATIONS`].
 
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Bump on any **incompatible** schema change (renamed fields, removed
 /// fields with semantic load-bearers, changed types). Additive
 /// changes don't need a bump — `#[serde(default)]` covers them.
 pub const SCHEMA_VERSION: u32 = 1;
 
+/// One schema step: rewrites a raw preferences object in place from the
+/// shape of version `n` to `n + 1`.
+type Migration = fn(&mut serde_json::Map<String, Value>);
+
+/// `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`, so the chain must
+/// always be `SCHEMA_VERSION - 1` long. Empty while the schema is at v1.
+const MIGRATIONS: &[Migration] = &[];
+
 /// Top-level preferences. Every nested field defaults so partial /
 /// older JSON files load cleanly.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -64,6 +81,29 @@ pub struct UserPreferences {
     /// own. See [`Self::client_id`].
     #[serde(default)]
     pub spotify_client_id: Option<String>,
+    /// Playlists to prefetch in the background on launch.
+    #[serde(default)]
+    pub warmup: WarmupPrefs,
+    /// Low-power rendering (glass → solid surfaces).
+    #[serde(default)]
+    pub effects: EffectsPrefs,
+    /// System-wide transport shortcuts.
+    #[serde(default)]
+    pub hotkeys: HotkeyPrefs,
+    /// UI language as a locale code (`"de"`, `"pt-BR"`), resolved against
+    /// the bundles in `<config>/opal/locales/`. `None` = English. See
+    /// [`crate::i18n`].
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Look lyrics up on LRCLIB (lrclib.net), which receives the playing
+    /// track's title, artist and length. Off until the user opts in from
+    /// settings or the lyrics page.
+    #[serde(default)]
+    pub lyrics_lookup: bool,
+    /// Top-level keys this build doesn't know — written by a newer build.
+    /// Carried through untouched so saving here doesn't erase them.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
 }
 
 fn default_version() -> u32 {
@@ -85,6 +125,12 @@ impl Default for UserPreferences {
             show_canvas: default_show_canvas(),
             cache_dir: None,
             spotify_client_id: None,
+            warmup: WarmupPrefs::default(),
+            effects: EffectsPrefs::default(),
+            hotkeys: HotkeyPrefs::default(),
+            locale: None,
+            lyrics_lookup: false,
+            extra: serde_json::Map::new(),
         }
     }
 }
@@ -186,6 +232,11 @@ pub struct AudioPrefs {
     /// loud masters from clipping. Applies from the next app start.
     #[serde(default = "default_normalize")]
     pub normalize: bool,
+    /// Audio output device, by the name the OS reports. `None` = the
+    /// system default. A device that's gone at launch falls back to the
+    /// default. Applies from the next app start.
+    #[serde(default)]
+    pub output_device: Option<String>,
 }
 
 fn default_volume() -> f32 {
@@ -201,11 +252,104 @@ impl Default for AudioPrefs {
         Self {
             volume: default_volume(),
             quality: AudioQuality::default(),
+            output_device: None,
             normalize: default_normalize(),
         }
     }
 }
 
+/// Launch-time cache warm-up. After auth the worker prefetches each
+/// marked playlist's metadata + first track page and the first
+/// [`artworks`](Self::artworks) covers into the disk cache, one playlist
+/// at a time, so opening them later is instant even on a slow network.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WarmupPrefs {
+    /// Global switch (settings). Off keeps the marks but skips the work.
+    #[serde(default = "default_warmup_enabled")]
+    pub enabled: bool,
+    /// Marked playlist ids, toggled from the library context menu.
+    /// [`crate::api::LIKED_SONGS_ID`] stands for Liked Songs.
+    #[serde(default)]
+    pub playlists: Vec<String>,
+    /// Covers to prefetch per playlist, in track order.
+    #[serde(default = "default_warmup_artworks")]
+    pub artworks: u32,
+}
+
+fn default_warmup_enabled() -> bool {
+    true
+}
+
+fn default_warmup_artworks() -> u32 {
+    24
+}
+
+impl Default for WarmupPrefs {
+    fn default() -> Self {
+        Self {
+            enabled: default_warmup_enabled(),
+            playlists: Vec::new(),
+            artworks: default_warmup_artworks(),
+        }
+    }
+}
+
+impl WarmupPrefs {
+    pub fn is_marked(&self, id: &str) -> bool {
+        self.playlists.iter().any(|p| p == id)
+    }
+
+    /// Mark or unmark `id`; returns the new state.
+    pub fn toggle(&mut self, id: &str) -> bool {
+        if self.is_marked(id) {
+            self.playlists.retain(|p| p != id);
+            false
+        } else {
+            self.playlists.push(id.to_string());
+            true
+        }
+    }
+}
+
+/// Low-power rendering. When active, the ambient backdrop's covers and
+/// frosted glass (and the sticky playlist header's glass) are replaced
+/// by solid, accent-tinted surfaces. See [`crate::model::PowerModel`].
+/// Both toggles are opt-in.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct EffectsPrefs {
+    /// "Reduce visual effects" — always on.
+    #[serde(default)]
+    pub reduce: bool,
+    /// Reduce automatically while running on battery.
+    #[serde(default)]
+    pub reduce_on_battery: bool,
+}
+
+/// Global shortcuts (see [`crate::hotkeys`]), read once at startup. Each
+/// binding is an accelerator string — modifiers (`ctrl`, `alt`, `shift`,
+/// `super`) then a key code (`Space`, `ArrowRight`, `KeyP`, …), joined by
+/// `+`; an empty string leaves that action unbound. All three start
+/// unbound — the natural chords clash with desktop workspace shortcuts.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HotkeyPrefs {
+    pub enabled: bool,
+    pub play_pause: String,
+    pub next: String,
+    pub previous: String,
+}
+
+impl Default for HotkeyPrefs {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            play_pause: String::new(),
+            next: String::new(),
+            previous: String::new(),
+        }
+    }
+}
+
 /// Streaming quality tier. Defaults to High (320 kbps — the ceiling any
 /// third-party client can stream; lossless rides DRM librespot can't
 /// decrypt). Low/Normal exist for constrained connections.
@@ -237,7 +381,7 @@ impl UserPreferences {
             return Self::default();
         };
         match fs::read_to_string(&path) {
-            Ok(text) => match serde_json::from_str::<Self>(&text) {
+            Ok(text) => match Self::parse(&text) {
                 Ok(prefs) => {
                     log::info!("loaded user prefs from {}", path.display());
                     prefs
@@ -255,6 +399,21 @@ impl UserPreferences {
         }
     }
 
+    /// Parse a preferences file, migrating an older schema forward first.
+    /// A file from a *newer* build is loaded as far as this build
+    /// understands it and keeps its version, and its unknown top-level
+    /// keys ride along in [`Self::extra`], so saving here hands the newer
+    /// build back its own settings rather than a re-stamped subset.
+    fn parse(text: &str) -> serde_json::Result<Self> {
+        let mut value: Value = serde_json::from_str(text)?;
+        if let Value::Object(map) = &mut value {
+            migrate(map, MIGRATIONS);
+        }
+        let mut prefs: Self = serde_json::from_value(value)?;
+        prefs.version = prefs.version.max(SCHEMA_VERSION);
+        Ok(prefs)
+    }
+
     /// Pretty-print to the on-disk JSON file. Creates the parent dir
     /// if missing. Best-effort — caller logs but does not propagate.
     pub fn save(&self) -> io::Result<()> {
@@ -271,6 +430,25 @@ impl UserPreferences {
     }
 }
 
+/// Run every step from the file's version up to the end of `steps`,
+/// stamping the version after each. Returns the version reached. A file
+/// without a `version` predates versioning and counts as v1.
+fn migrate(map: &mut serde_json::Map<String, Value>, steps: &[Migration]) -> u32 {
+    let mut version = map.get("version").and_then(Value::as_u64).unwrap_or(1).max(1) as u32;
+    let latest = steps.len() as u32 + 1;
+    if version > latest {
+        log::warn!("prefs written by a newer build (v{version}); loading best-effort");
+        return version;
+    }
+    for step in &steps[(version - 1) as usize..] {
+        step(map);
+        version += 1;
+        map.insert("version".into(), Value::from(version));
+        log::info!("migrated prefs to schema v{version}");
+    }
+    version
+}
+
 /// `<config_dir>/opal/preferences.json`. `None` if the OS doesn't
 /// expose a config dir (extremely rare; e.g. some headless containers).
 pub fn preferences_path() -> Option<PathBuf> {
@@ -300,14 +478,73 @@ mod tests {
         assert_eq!(prefs.panels.sidebar_w, 280.0);
         assert_eq!(prefs.panels.now_playing_w, 340.0, "default kicks in");
         assert_eq!(prefs.audio.volume, 0.8);
+        assert!(prefs.hotkeys.enabled);
         assert_eq!(prefs.version, SCHEMA_VERSION);
     }
 
+    #[test]
+    fn migration_chain_is_complete() {
+        assert_eq!(MIGRATIONS.len() as u32, SCHEMA_VERSION - 1);
+    }
+
+    #[test]
+    fn migrate_runs_remaining_steps_in_order() {
+        // Hypothetical v1 → v2 (rename) and v2 → v3 (nest) steps.
+        fn rename(m: &mut serde_json::Map<String, Value>) {
+            if let Some(v) = m.remove("vol") {
+                m.insert("volume".into(), v);
+            }
+        }
+        fn nest(m: &mut serde_json::Map<String, Value>) {
+            if let Some(v) = m.remove("volume") {
+                m.insert("audio".into(), serde_json::json!({ "volume": v }));
+            }
+        }
+        let steps: &[Migration] = &[rename, nest];
+        let mut map = serde_json::json!({ "version": 1, "vol": 0.5 })
+            .as_object()
+            .cloned()
+            .unwrap();
+        assert_eq!(migrate(&mut map, steps), 3);
+        assert_eq!(map["audio"]["volume"], 0.5);
+        assert_eq!(map["version"], 3);
+        // Already current: nothing runs.
+        assert_eq!(migrate(&mut map, steps), 3);
+        // From a newer build: left untouched.
+        let mut newer = serde_json::json!({ "version": 9 }).as_object().cloned().unwrap();
+        assert_eq!(migrate(&mut newer, steps), 9);
+    }
+
+    #[test]
+    fn newer_file_still_loads() {
+        let prefs = UserPreferences::parse(r#"{"version": 7, "future_knob": true}"#).unwrap();
+        assert_eq!(prefs.version, 7);
+        assert_eq!(prefs.panels.sidebar_w, 320.0);
+        // Saved back verbatim — the newer build's key isn't dropped.
+        let saved = serde_json::to_value(&prefs).unwrap();
+        assert_eq!(saved["future_knob"], Value::Bool(true));
+        assert_eq!(saved["version"], 7);
+    }
+
     #[test]
     fn empty_object_yields_full_defaults() {
         let prefs: UserPreferences = serde_json::from_str("{}").unwrap();
         assert_eq!(prefs.panels.sidebar_w, 320.0);
         assert_eq!(prefs.window.width, None);
         assert!(!prefs.window.maximized);
+        assert!(prefs.warmup.enabled);
+        assert!(prefs.warmup.playlists.is_empty());
+        assert!(!prefs.effects.reduce);
+        assert!(!prefs.effects.reduce_on_battery);
+        assert!(!prefs.lyrics_lookup);
+    }
+
+    #[test]
+    fn warmup_toggle_marks_and_unmarks() {
+        let mut w = WarmupPrefs::default();
+        assert!(w.toggle("abc"));
+        assert!(w.is_marked("abc"));
+        assert!(!w.toggle("abc"));
+        assert!(w.playlists.is_empty());
     }
 }
@@ -0,0 +1,194 @@
+//! Sorting + grouping helpers shared by the list views: a collation key
+//! for titles/names, Spotify's variable-precision release dates, and a
+//! contiguous group-by that yields header sections.
+//!
+//! Collation is a pragmatic approximation of a music library's order
+//! rather than full locale-aware UCA: case-insensitive, Latin diacritics
+//! folded (`Beyoncé` files with `Beyonce`), a leading "The " ignored, and
+//! digit runs compared numerically (`Track 2` before `Track 10`).
+//!
+//! The artist discography is the only consumer so far: newest-first by
+//! release date, ties by title, split into one section per decade. Track
+//! sorts (by duration, artist) and added-date or by-album sections aren't
+//! here; they come with the first list view that offers those orders.
+
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::ops::Range;
+use std::str::Chars;
+
+/// Sort key for a text field: lowercased, diacritics folded, leading
+/// article dropped. Build once per item when sorting large lists.
+pub fn collate_key(s: &str) -> String {
+    let trimmed = s.trim_start();
+    let body = match trimmed.get(..4) {
+        Some(p) if p.eq_ignore_ascii_case("the ") && trimmed.len() > 4 => &trimmed[4..],
+        _ => trimmed,
+    };
+    let mut out = String::with_capacity(body.len());
+    for c in body.chars().flat_map(char::to_lowercase) {
+        match fold(c) {
+            Some(f) => out.push_str(f),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// ASCII base of a lowercase Latin-1 / Latin Extended-A letter.
+fn fold(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'è'..='ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ì'..='ï' | 'ī' | 'į' | 'ı' => "i",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò'..='ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ß' => "ss",
+        'ś' | 'š' | 'ş' => "s",
+        'ù'..='ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ł' => "l",
+        _ => return None,
+    })
+}
+
+/// Compare two collation keys, treating digit runs as numbers.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (na, nb) = (digit_run(&mut a), digit_run(&mut b));
+                let (na, nb) = (na.trim_start_matches('0'), nb.trim_start_matches('0'));
+                // Same length without leading zeros → lexicographic is numeric.
+                let ord = na.len().cmp(&nb.len()).then_with(|| na.cmp(nb));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+fn digit_run(it: &mut Peekable<Chars<'_>>) -> String {
+    let mut n = String::new();
+    while let Some(d) = it.next_if(char::is_ascii_digit) {
+        n.push(d);
+    }
+    n
+}
+
+/// Text comparison for display order (see the module docs).
+pub fn cmp_text(a: &str, b: &str) -> Ordering {
+    natural_cmp(&collate_key(a), &collate_key(b))
+}
+
+/// A Spotify release date: `YYYY-MM-DD`, `YYYY-MM`, or `YYYY` depending
+/// on the album's precision. Orders chronologically, with a coarser date
+/// sorting before finer ones in the same period (`2020` < `2020-05`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReleaseDate {
+    pub year: u16,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+impl ReleaseDate {
+    /// `None` for an empty or unparseable date.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next().and_then(|m| m.parse().ok());
+        let day = parts.next().and_then(|d| d.parse().ok());
+        Some(Self { year, month, day })
+    }
+
+    /// Decade label (`format.decade`): `1990s`.
+    pub fn decade(&self) -> String {
+        crate::i18n::tr_n("format.decade", self.year / 10 * 10)
+    }
+}
+
+/// Compare two raw release-date strings; undated sorts first.
+pub fn cmp_release(a: &str, b: &str) -> Ordering {
+    ReleaseDate::parse(a).cmp(&ReleaseDate::parse(b))
+}
+
+/// A run of consecutive items sharing a header label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub title: String,
+    pub items: Range<usize>,
+}
+
+/// Split already-sorted `items` into sections of consecutive equal
+/// labels — the shape a sticky-header list renders (one header row per
+/// section, then its items).
+pub fn group_by<T>(items: &[T], label: impl Fn(&T) -> String) -> Vec<Section> {
+    let mut out: Vec<Section> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        let title = label(item);
+        match out.last_mut() {
+            Some(s) if s.title == title => s.items.end = i + 1,
+            _ => out.push(Section {
+                title,
+                items: i..i + 1,
+            }),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collation_folds_case_accents_and_articles() {
+        assert_eq!(collate_key("The Beatles"), "beatles");
+        assert_eq!(collate_key("Beyoncé"), "beyonce");
+        assert_eq!(collate_key("The"), "the");
+        assert_eq!(cmp_text("Ólafur Arnalds", "Oasis"), Ordering::Greater);
+        assert_eq!(cmp_text("abba", "ABBA"), Ordering::Equal);
+    }
+
+    #[test]
+    fn natural_cmp_orders_digit_runs_numerically() {
+        assert_eq!(natural_cmp("track 2", "track 10"), Ordering::Less);
+        assert_eq!(natural_cmp("track 010", "track 10"), Ordering::Equal);
+        assert_eq!(natural_cmp("a", "ab"), Ordering::Less);
+    }
+
+    #[test]
+    fn release_dates_order_across_precisions() {
+        assert_eq!(cmp_release("2020", "2020-05"), Ordering::Less);
+        assert_eq!(cmp_release("2020-05-09", "2020-05"), Ordering::Greater);
+        assert_eq!(cmp_release("1999-12-31", "2000"), Ordering::Less);
+        assert_eq!(cmp_release("", "1960"), Ordering::Less);
+        assert_eq!(ReleaseDate::parse("1994-03").unwrap().decade(), "1990s");
+    }
+
+    #[test]
+    fn group_by_makes_contiguous_sections() {
+        let years = ["1991", "1995", "2003", "2008", "2011"];
+        let sections = group_by(&years, |y| ReleaseDate::parse(y).unwrap().decade());
+        let titles: Vec<_> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["1990s", "2000s", "2010s"]);
+        assert_eq!(sections[1].items, 2..4);
+        assert!(group_by(&[] as &[&str], |s| s.to_string()).is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use cpal::traits::{DeviceTrait, HostTrait};
 use librespot_connect::{ConnectConfig, Spirc};
 use librespot_core::Session;
 use librespot_core::authentication::Credentials;
@@ -35,13 +36,16 @@ pub struct SpircBootstrap {
 /// it, every transfer to Opal snaps the user back to librespot's
 /// 50% default. `quality` maps the persisted streaming-quality pref to
 /// the librespot bitrate tier (applies from the next session, i.e. app
-/// start — the player is built once here).
+/// start — the player is built once here). `output_device` names the sink
+/// to open (see [`output_devices`]); `None` or a device that's no longer
+/// present opens the system default.
 pub async fn start(
     session: Session,
     credentials: Credentials,
     initial_volume: f32,
     quality: crate::prefs::AudioQuality,
     normalize: bool,
+    output_device: Option<String>,
 ) -> Result<SpircBootstrap, AuthError> {
     // External cluster subscription must land BEFORE Spirc's own
     // dealer subs to be sure we register first in the listener map.
@@ -81,6 +85,15 @@ pub async fn start(
     // so 320 is the ceiling for any third-party Connect client today.
     let backend = audio_backend::find(Some("rodio".to_string()))
         .ok_or_else(|| AuthError::Server("rodio audio backend unavailable".into()))?;
+    // The rodio backend exits the process on an unknown device name, so a
+    // saved device that has since been unplugged is dropped here instead.
+    let device = output_device.filter(|name| {
+        let present = output_devices().contains(name);
+        if !present {
+            log::warn!("audio output {name:?} not found, using the system default");
+        }
+        present
+    });
     let player_config = PlayerConfig {
         bitrate: match quality {
             crate::prefs::AudioQuality::Low => Bitrate::Bitrate96,
@@ -108,7 +121,7 @@ pub async fn start(
     // the full decoded precision to the OS instead of quantising to 16-bit
     // at our sink. No downside, strictly more faithful to the source.
     let player = Player::new(player_config, session.clone(), volume_getter, move || {
-        backend(None, AudioFormat::F32)
+        backend(device.clone(), AudioFormat::F32)
     });
     // Grab the event stream before Spirc consumes the player.
     let player_events = player.get_player_event_channel();
@@ -124,3 +137,16 @@ pub async fn start(
         player_events,
     })
 }
+
+/// Names of the audio output devices on the default host, in the OS's
+/// order — what the settings picker offers and what `start` opens by.
+/// Empty when the host can't enumerate (the default device still works).
+pub fn output_devices() -> Vec<String> {
+    match cpal::default_host().output_devices() {
+        Ok(devices) => devices.filter_map(|d| d.name().ok()).collect(),
+        Err(e) => {
+            log::warn!("listing audio outputs failed: {e}");
+            Vec::new()
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Debug-only **widget gallery** — every shared widget in [`crate::widgets`]
+//! laid out in each state it can be built in, one tab per widget family,
+//! so a styling change can be checked against all of them at once instead
+//! of hunting for the one screen that shows each.
+//!
+//! Launch a debug build with `--gallery [tab]` (e.g. `--gallery toggles`);
+//! the optional tab name opens straight onto that page, which makes a tab
+//! a stable target for the automation harness's screenshots. Hover and
+//! pressed looks are live (the engine owns them), so those are checked by
+//! pointing at the widget; static states (on/off, selected, disabled,
+//! open) are built side by side. Like [`super::stress`] the view skips
+//! token load + auth entirely, and it is compiled out of release builds.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Instant;
+
+use opal_gfx::{Align, Len, Scene, Signal, Timeline};
+
+use crate::widgets::button::{ButtonTone, pill_button};
+use crate::widgets::chip::chip;
+use crate::widgets::dropdown::Dropdown;
+use crate::widgets::icon::{self, Icon, IconSet};
+use crate::widgets::spinner::{Spin, progress_ring, spinner};
+use crate::widgets::thumb::thumb;
+use crate::widgets::toggle::{checkbox, toggle_switch};
+use crate::widgets::tokens as t;
+
+/// One gallery page per widget family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tab {
+    #[default]
+    Buttons,
+    Toggles,
+    Inputs,
+    Chips,
+    Icons,
+    Thumbs,
+    Loaders,
+}
+
+impl Tab {
+    const ALL: [Tab; 7] = [
+        Tab::Buttons,
+        Tab::Toggles,
+        Tab::Inputs,
+        Tab::Chips,
+        Tab::Icons,
+        Tab::Thumbs,
+        Tab::Loaders,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Tab::Buttons => "Buttons",
+            Tab::Toggles => "Toggles",
+            Tab::Inputs => "Inputs",
+            Tab::Chips => "Chips",
+            Tab::Icons => "Icons",
+            Tab::Thumbs => "Thumbs",
+            Tab::Loaders => "Loaders",
+        }
+    }
+
+    /// Case-insensitive tab lookup by label; unknown names open the first.
+    fn parse(name: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|tab| tab.label().eq_ignore_ascii_case(name.trim()))
+            .unwrap_or_default()
+    }
+
+    /// `--gallery` (first tab) or `--gallery TAB` / `--gallery=TAB` from the
+    /// process args. `None` when the flag is absent.
+    pub fn from_args() -> Option<Self> {
+        let mut args = std::env::args().skip(1);
+        while let Some(a) = args.next() {
+            if let Some(name) = a.strip_prefix("--gallery=") {
+                return Some(Self::parse(name));
+            }
+            if a == "--gallery" {
+                let next = args.next().filter(|n| !n.starts_with("--"));
+                return Some(next.map(|n| Self::parse(&n)).unwrap_or_default());
+            }
+        }
+        None
+    }
+}
+
+/// Icons per row on the Icons tab.
+const ICONS_PER_ROW: usize = 12;
+
+/// Options for the dropdown specimens.
+const OPTIONS: &[&str] = &["Low", "Normal", "High", "Very high"];
+
+pub struct GalleryView {
+    icons: Rc<IconSet>,
+    rebuild: Rc<Cell<bool>>,
+    tab: Rc<Cell<Tab>>,
+    /// The gallery has no album art to derive an accent from, so widgets
+    /// take the brand accent.
+    accent: Signal<[f32; 4]>,
+    /// Live state for the interactive specimens (the static-state ones are
+    /// built from constants).
+    switch_on: Signal<bool>,
+    switch_off: Signal<bool>,
+    dropdown_open: Rc<Cell<bool>>,
+    dropdown_selected: Rc<Cell<usize>>,
+    /// Clock for the spinner specimens — the gallery's own, turned only
+    /// while the Loaders tab is open.
+    spin: Spin,
+}
+
+impl GalleryView {
+    pub fn new(tab: Tab, icons: Rc<IconSet>, rebuild: Rc<Cell<bool>>) -> Self {
+        log::info!("widget gallery: opening on {}", tab.label());
+        Self {
+            icons,
+            rebuild,
+            tab: Rc::new(Cell::new(tab)),
+            accent: Signal::new(t::ACCENT),
+            switch_on: Signal::new(true),
+            switch_off: Signal::new(false),
+            dropdown_open: Rc::new(Cell::new(false)),
+            dropdown_selected: Rc::new(Cell::new(1)),
+            spin: Spin::new(),
+        }
+    }
+
+    /// Per-frame (from the host's frame hook): keeps the spinners turning.
+    pub fn tick(&self, tl: &mut Timeline, now: Instant) {
+        self.spin.tick(self.tab.get() == Tab::Loaders, tl, now);
+    }
+
+    pub fn build(&self, s: &mut Scene) {
+        s.col("gallery_root")
+            .fill()
+            .rgba(t::BG[0], t::BG[1], t::BG[2], 1.0)
+            .child(|root| {
+                self.tabs(root);
+                root.col("gallery_page")
+                    .w(Len::Fill)
+                    .h(Len::Fill)
+                    .pad(t::SP_6)
+                    .gap(t::SP_6)
+                    .scroll_y()
+                    .child(|page| match self.tab.get() {
+                        Tab::Buttons => self.buttons(page),
+                        Tab::Toggles => self.toggles(page),
+                        Tab::Inputs => self.inputs(page),
+                        Tab::Chips => self.chips(page),
+                        Tab::Icons => self.icon_grid(page),
+                        Tab::Thumbs => self.thumbs(page),
+                        Tab::Loaders => self.loaders(page),
+                    });
+            });
+    }
+
+    /// The tab strip — chips, so the strip itself is a chip specimen too.
+    fn tabs(&self, s: &mut Scene) {
+        s.row(())
+            .w(Len::Fill)
+            .pad_xy(t::SP_6, t::SP_4)
+            .gap(t::SP_2)
+            .align(Align::Center)
+            .color(t::PANEL)
+            .child(|r| {
+                for tab in Tab::ALL {
+                    let current = self.tab.clone();
+                    let rebuild = self.rebuild.clone();
+                    r.row(())
+                        .on_click(move |_| {
+                            current.set(tab);
+                            rebuild.set(true);
+                        })
+                        .child(|c| chip(c, tab.label(), tab == self.tab.get(), &self.accent));
+                }
+            });
+    }
+
+    fn buttons(&self, s: &mut Scene) {
+        let icons = &self.icons;
+        section(
+            s,
+            "Pill button · neutral / danger, with and without icon",
+            |r| {
+                for (label, icon, tone) in [
+                    ("Sign out", None, ButtonTone::Neutral),
+                    ("Back", Some(Icon::ChevronLeft), ButtonTone::Neutral),
+                    ("Reset preferences", None, ButtonTone::Danger),
+                    ("Remove", Some(Icon::Close), ButtonTone::Danger),
+                ] {
+                    pill_button(r, icons, label, icon, tone, |_| {});
+                }
+            },
+        );
+    }
+
+    fn toggles(&self, s: &mut Scene) {
+        let noop: Rc<dyn Fn(bool)> = Rc::new(|_| {});
+        section(s, "Switch · live / on disabled / off disabled", |r| {
+            toggle_switch(r, &self.switch_on, &self.accent, false, noop.clone());
+            toggle_switch(r, &self.switch_off, &self.accent, false, noop.clone());
+            toggle_switch(r, &Signal::new(true), &self.accent, true, noop.clone());
+            toggle_switch(r, &Signal::new(false), &self.accent, true, noop.clone());
+        });
+        section(
+            s,
+            "Check box · on / off / on disabled / off disabled",
+            |r| {
+                for (on, disabled) in [(true, false), (false, false), (true, true), (false, true)] {
+                    checkbox(r, &self.icons, on, &self.accent, disabled);
+                }
+            },
+        );
+    }
+
+    fn inputs(&self, s: &mut Scene) {
+        section(s, "Dropdown · live / open", |r| {
+            let toggle: Rc<dyn Fn()> = {
+                let open = self.dropdown_open.clone();
+                let rebuild = self.rebuild.clone();
+                Rc::new(move || {
+                    open.set(!open.get());
+                    rebuild.set(true);
+                })
+            };
+            let select: Rc<dyn Fn(usize)> = {
+                let selected = self.dropdown_selected.clone();
+                Rc::new(move |i| selected.set(i))
+            };
+            r.col(()).w_px(240.0).child(|c| {
+                Dropdown {
+                    options: OPTIONS,
+                    selected: self.dropdown_selected.get(),
+                    open: self.dropdown_open.get(),
+                    accent: &self.accent,
+                    on_toggle: toggle,
+                    on_select: select,
+                }
+                .view(c, &self.icons);
+            });
+            // A permanently open copy, so the list styling is visible
+            // without a click (and in a screenshot).
+            r.col(()).w_px(240.0).child(|c| {
+                Dropdown {
+                    options: OPTIONS,
+                    selected: 2,
+                    open: true,
+                    accent: &self.accent,
+                    on_toggle: Rc::new(|| {}),
+                    on_select: Rc::new(|_| {}),
+                }
+                .view(c, &self.icons);
+            });
+        });
+        section(s, "Text field · placeholder / filled", |r| {
+            for (name, initial) in [("gallery_field_empty", ""), ("gallery_field", "Daft Punk")] {
+                r.text_field(name, initial, t::TEXT_SM)
+                    .placeholder("Search")
+                    .text_color(t::TEXT)
+                    .placeholder_color(t::TEXT_DIM)
+                    .w_px(240.0)
+                    .h_px(t::CHIP_H)
+                    .pad_xy(t::SP_3, 0.0)
+                    .align(Align::Center)
+                    .radius(t::R_FULL)
+                    .color(t::PANEL_HI);
+            }
+        });
+    }
+
+    fn chips(&self, s: &mut Scene) {
+        section(s, "Chip · selected / unselected", |r| {
+            chip(r, "Playlists", true, &self.accent);
+            chip(r, "Albums", false, &self.accent);
+            chip(r, "Artists", false, &self.accent);
+        });
+    }
+
+    fn icon_grid(&self, s: &mut Scene) {
+        for (title, size, color) in [
+            ("Icons · small", t::ICON_SM, t::TEXT),
+            ("Icons · medium", t::ICON_MD, t::TEXT),
+            ("Icons · medium, dim", t::ICON_MD, t::TEXT_DIM),
+            ("Icons · medium, accent", t::ICON_MD, t::ACCENT),
+        ] {
+            // Section rows don't wrap, so the set is dealt into short rows.
+            section(s, title, |r| {
+                r.col(()).gap(t::SP_2).child(|grid| {
+                    for chunk in icon::ALL.chunks(ICONS_PER_ROW) {
+                        grid.row(()).gap(t::SP_2).child(|row| {
+                            for &ic in chunk {
+                                self.icon_cell(row, ic, size, color);
+                            }
+                        });
+                    }
+                });
+            });
+        }
+    }
+
+    /// One icon on a hover plate, named in its tooltip.
+    fn icon_cell(&self, s: &mut Scene, ic: Icon, size: f32, color: [f32; 4]) {
+        let name = format!("{ic:?}");
+        s.row(())
+            .w_px(t::TOPBAR_BTN)
+            .h_px(t::TOPBAR_BTN)
+            .center()
+            .radius(t::R_FULL)
+            .hover_color(t::PANEL_HI)
+            .hover_hint(&name)
+            .child(|b| self.icons.render(b, ic, size, color));
+    }
+
+    fn thumbs(&self, s: &mut Scene) {
+        section(s, "Thumb · placeholder at list / card / hero sizes", |r| {
+            for (size, radius) in [(40.0, t::R_SM), (120.0, t::R_LG), (220.0, t::R_XL)] {
+                thumb(r, None, size, radius);
+            }
+        });
+    }
+
+    fn loaders(&self, s: &mut Scene) {
+        section(s, "Spinner · small / medium / large, dim / accent", |r| {
+            for size in [t::ICON_SM, t::ICON_MD, 40.0] {
+                spinner(r, &self.spin, size, t::TEXT_DIM);
+                spinner(r, &self.spin, size, t::ACCENT);
+            }
+        });
+        section(s, "Progress ring · 0 / 30 / 65 / 100 %", |r| {
+            for value in [0.0, 0.3, 0.65, 1.0] {
+                progress_ring(r, &Signal::new(value), 40.0, t::ACCENT);
+            }
+        });
+    }
+}
+
+/// A titled specimen row; `body` lays its widgets out left to right
+/// (no wrapping — keep a row to what fits a default-size window).
+fn section(s: &mut Scene, title: &str, body: impl FnOnce(&mut Scene)) {
+    s.col(()).w(Len::Fill).gap(t::SP_3).child(|c| {
+        c.text((), title, t::TEXT_SM).color(t::TEXT_DIM);
+        c.row(())
+            .w(Len::Fill)
+            .gap(t::SP_4)
+            .align(Align::Center)
+            .child(body);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tab_names_parse_case_insensitively() {
+        assert_eq!(Tab::parse("toggles"), Tab::Toggles);
+        assert_eq!(Tab::parse(" ICONS "), Tab::Icons);
+        assert_eq!(Tab::parse("sliders"), Tab::Buttons);
+        for tab in Tab::ALL {
+            assert_eq!(Tab::parse(tab.label()), tab);
+        }
+    }
+}
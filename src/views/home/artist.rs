@@ -3,14 +3,18 @@
 //!
 //! Lighter than the playlist/album pages: artists have a bounded album
 //! count, so this is a plain `scroll_y` column (no virtualised list) and
-//! no collapsing header. Each album tile opens its [`MainNav::Album`] page,
-//! reusing [`crate::views::home::main_pane::tile`].
+//! no collapsing header. The discography is split into one strip per
+//! decade ([`crate::sort::group_by`]); each album tile opens its
+//! [`MainNav::Album`] page, reusing [`crate::views::home::main_pane::tile`].
 
 use std::rc::Rc;
 
 use opal_gfx::{Align, ImageHandle, Justify, Len, Overflow, Scene, Signal};
 
 use crate::api::PlayTarget;
+use crate::format;
+use crate::i18n::tr;
+use crate::sort::{self, ReleaseDate};
 use crate::views::MainNav;
 use crate::views::home::{NavFn, PlayFn};
 use crate::widgets::icon::{Icon, IconSet};
@@ -103,16 +107,16 @@ pub fn view(
                             }
                         });
                     hero.col(()).gap(t::SP_2).justify(Justify::End).child(|m| {
-                        m.text((), "Artist", 12.0).color(t::TEXT_DIM);
+                        m.text((), tr("kind.artist"), 12.0).color(t::TEXT_DIM);
                         let title = if data.name.is_empty() && data.loading {
-                            "Loading\u{2026}"
+                            tr("page.loading")
                         } else {
-                            &data.name
+                            data.name.clone()
                         };
                         m.text((), title, 32.0).color(t::TEXT).max_width_px(520.0);
                         if data.followers > 0 {
-                            m.text((), fmt_followers(data.followers), 12.0)
-                                .color(t::TEXT_DIM);
+                            let followers = format::count_compact(data.followers, "follower");
+                            m.text((), followers, 12.0).color(t::TEXT_DIM);
                         }
                     });
                 });
@@ -124,7 +128,7 @@ pub fn view(
                     .h_px(t::SP_7)
                     .align(Align::Center)
                     .child(|h| {
-                        h.text((), "Popular", 18.0).color(t::TEXT);
+                        h.text((), tr("artist.popular"), 18.0).color(t::TEXT);
                     });
                 for (i, track) in data.popular.iter().enumerate() {
                     popular_row(c, i as u32, track, &on_play);
@@ -137,33 +141,45 @@ pub fn view(
                 .h_px(t::SP_7)
                 .align(Align::Center)
                 .child(|h| {
-                    h.text((), "Discography", 18.0).color(t::TEXT);
+                    h.text((), tr("artist.discography"), 18.0).color(t::TEXT);
                 });
             if data.albums.is_empty() {
                 if !data.loading {
-                    c.text((), "No releases", 14.0).color(t::TEXT_DIM);
+                    c.text((), tr("artist.no_releases"), 14.0).color(t::TEXT_DIM);
                 }
             } else {
-                // Reuse the shared card strip (cover + arrows-on-hover).
-                let cards = data
-                    .albums
-                    .iter()
-                    .map(|al| crate::views::home::main_pane::Card {
-                        title: al.name.clone(),
-                        subtitle: al.year.clone(),
-                        cover: al.cover.clone(),
-                        target: Some(MainNav::Album { id: al.id.clone() }),
-                        menu: None,
-                    })
-                    .collect();
-                crate::views::home::main_pane::card_row(
-                    c,
-                    icons,
-                    on_navigate.clone(),
-                    None,
-                    None,
-                    cards,
-                );
+                // One strip per decade (albums arrive newest-first), each
+                // under its own small header — long careers read as eras
+                // instead of one endless row.
+                let sections = sort::group_by(&data.albums, |al| {
+                    ReleaseDate::parse(&al.year)
+                        .map(|d| d.decade())
+                        .unwrap_or_default()
+                });
+                for section in sections {
+                    if !section.title.is_empty() {
+                        c.text((), &section.title, 14.0).color(t::TEXT_DIM);
+                    }
+                    // Reuse the shared card strip (cover + arrows-on-hover).
+                    let cards = data.albums[section.items]
+                        .iter()
+                        .map(|al| crate::views::home::main_pane::Card {
+                            title: al.name.clone(),
+                            subtitle: al.year.clone(),
+                            cover: al.cover.clone(),
+                            target: Some(MainNav::Album { id: al.id.clone() }),
+                            menu: None,
+                        })
+                        .collect();
+                    crate::views::home::main_pane::card_row(
+                        c,
+                        icons,
+                        on_navigate.clone(),
+                        None,
+                        None,
+                        cards,
+                    );
+                }
             }
         });
 }
@@ -228,15 +244,3 @@ fn popular_row(s: &mut Scene, index: u32, track: &ArtistTrack, on_play: &PlayFn)
                 });
         });
 }
-
-/// Compact follower count: "1.2M followers" / "12.3K followers" / "742
-/// followers".
-fn fmt_followers(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M followers", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K followers", n as f64 / 1_000.0)
-    } else {
-        format!("{n} followers")
-    }
-}
@@ -1,15 +1,20 @@
-//! Right-click context menu for track rows.
+//! Right-click context menu for track rows and library playlists.
 //!
 //! Rendered last in the Home scene (on top of everything). When open, a
 //! full-window transparent scrim captures the next click/right-click to
 //! dismiss, and a small menu box is anchored at the cursor with the
-//! track's actions: Add to queue (works on any device, remote included),
-//! and Go to album / Go to artist when those ids are known.
+//! target's actions: Add to queue (works on any device, remote included),
+//! Go to album / Go to artist when those ids are known, Copy link, and the
+//! warm-up mark for a library playlist. Near the right/bottom window edge
+//! the box flips to the other side of the cursor (like a native menu) so it
+//! never opens partly off-window.
 
 use std::rc::Rc;
 
-use opal_gfx::{Align, Len, Scene};
+use opal_gfx::{Align, EventCtx, Len, Scene};
 
+use crate::api::{LIKED_SONGS_ID, share_url};
+use crate::i18n::tr;
 use crate::model::MenuModel;
 use crate::views::MainNav;
 use crate::views::home::NavFn;
@@ -17,22 +22,81 @@ use crate::widgets::tokens as t;
 
 /// Menu width (logical px).
 const MENU_W: f32 = 200.0;
+/// Row height, gap between rows, inner padding and border width — shared
+/// by the box in [`view`] and [`menu_height`], which sizes it for the
+/// edge flip before it's laid out.
+const ITEM_H: f32 = t::SP_9;
+const GAP: f32 = t::SP_0_5;
+const PAD: f32 = t::SP_1;
+const BORDER_W: f32 = 1.0;
+
+/// A row's click action (the menu closes after it runs).
+type Action = Rc<dyn Fn(&mut EventCtx)>;
+
+fn action(f: impl Fn(&mut EventCtx) + 'static) -> Action {
+    Rc::new(f)
+}
 
 /// Render the context menu if open. `on_add_queue(uri)` enqueues the
-/// track; `on_navigate` opens album/artist; `on_close` dismisses (both
-/// the scrim and every action close it).
+/// track; `on_navigate` opens album/artist; `on_copy_link(ctx, url)` puts
+/// a share link on the clipboard; `on_toggle_warm(id)` flips a playlist's
+/// warm-up mark; `on_close` dismisses (both the scrim and every action
+/// close it).
 pub fn view(
     s: &mut Scene,
     menu: &MenuModel,
     on_add_queue: Rc<dyn Fn(String)>,
     on_navigate: NavFn,
+    on_copy_link: Rc<dyn Fn(&mut EventCtx, String)>,
+    on_toggle_warm: Rc<dyn Fn(String)>,
     on_close: Rc<dyn Fn()>,
 ) {
     if !menu.open.get() {
         return;
     }
-    let pos = menu.pos.get();
     let target = menu.target.borrow().clone();
+    // A track row links the track; a library row links its playlist
+    // (Liked Songs is private to the account — no link).
+    let link = share_url(&target.uri).or_else(|| {
+        (target.playlist_id != LIKED_SONGS_ID)
+            .then(|| share_url(&format!("spotify:playlist:{}", target.playlist_id)))
+            .flatten()
+    });
+    // The rows (label key + action), built once: `view` renders exactly
+    // this list and `menu_height` sizes the box from its length. Each
+    // action's click also closes the menu (see the loop below).
+    let mut actions: Vec<(&'static str, Action)> = Vec::new();
+    if !target.uri.is_empty() {
+        let uri = target.uri.clone();
+        actions.push(("menu.add_queue", action(move |_| on_add_queue(uri.clone()))));
+    }
+    if !target.album_id.is_empty() {
+        let nav = on_navigate.clone();
+        let id = target.album_id.clone();
+        let go = action(move |ctx| nav(ctx, MainNav::Album { id: id.clone() }));
+        actions.push(("menu.go_album", go));
+    }
+    if !target.artist_id.is_empty() {
+        let nav = on_navigate;
+        let id = target.artist_id.clone();
+        let go = action(move |ctx| nav(ctx, MainNav::Artist { id: id.clone() }));
+        actions.push(("menu.go_artist", go));
+    }
+    if let Some(url) = link {
+        actions.push(("menu.copy_link", action(move |ctx| on_copy_link(ctx, url.clone()))));
+    }
+    // Warm-up mark (library playlists).
+    if !target.playlist_id.is_empty() {
+        let id = target.playlist_id.clone();
+        let key = if target.warm {
+            "menu.no_warm_up"
+        } else {
+            "menu.warm_up"
+        };
+        actions.push((key, action(move |_| on_toggle_warm(id.clone()))));
+    }
+    let size = [MENU_W, menu_height(actions.len())];
+    let pos = fit_to_window(menu.pos.get(), size, menu.bounds.get());
 
     // Full-window scrim: transparent but click/right-click-absorbing, so
     // the next press anywhere outside the menu dismisses it.
@@ -53,46 +117,40 @@ pub fn view(
         .w_px(MENU_W)
         .rgba(t::PANEL_HI[0], t::PANEL_HI[1], t::PANEL_HI[2], 1.0)
         .radius(t::R_MD)
-        .border(1.0, t::BORDER)
-        .pad(t::SP_1)
-        .gap(t::SP_0_5)
+        .border(BORDER_W, t::BORDER)
+        .pad(PAD)
+        .gap(GAP)
         .child(move |m| {
-            // Add to queue.
-            let uri = target.uri.clone();
-            let add = on_add_queue.clone();
-            let close = on_close.clone();
-            item(m, "Add to queue", move |_| {
-                add(uri.clone());
-                close();
-            });
-            // Go to album.
-            if !target.album_id.is_empty() {
-                let nav = on_navigate.clone();
-                let id = target.album_id.clone();
+            for (key, act) in actions {
                 let close = on_close.clone();
-                item(m, "Go to album", move |ctx| {
-                    nav(ctx, MainNav::Album { id: id.clone() });
-                    close();
-                });
-            }
-            // Go to artist.
-            if !target.artist_id.is_empty() {
-                let nav = on_navigate.clone();
-                let id = target.artist_id.clone();
-                let close = on_close.clone();
-                item(m, "Go to artist", move |ctx| {
-                    nav(ctx, MainNav::Artist { id: id.clone() });
+                item(m, &tr(key), move |ctx| {
+                    act(ctx);
                     close();
                 });
             }
         });
 }
 
+/// Outer height of a menu holding `items` rows: rows + gaps + padding +
+/// the border on each side — the same constants [`view`] builds with.
+fn menu_height(items: usize) -> f32 {
+    let n = items as f32;
+    n * ITEM_H + (n - 1.0).max(0.0) * GAP + 2.0 * (PAD + BORDER_W)
+}
+
+/// Anchor a `size` box at the cursor `pos`, flipping it to the left/top of
+/// the cursor on any axis where it would overflow `bounds` (all logical
+/// px). Never pushes past the window's top-left corner.
+fn fit_to_window(pos: [f32; 2], size: [f32; 2], bounds: [f32; 2]) -> [f32; 2] {
+    let axis = |p: f32, s: f32, b: f32| if p + s > b { (p - s).max(0.0) } else { p };
+    [axis(pos[0], size[0], bounds[0]), axis(pos[1], size[1], bounds[1])]
+}
+
 /// One menu row — a hover-highlighted label with a click action.
-fn item(s: &mut Scene, label: &str, on_click: impl Fn(&mut opal_gfx::EventCtx) + 'static) {
+fn item(s: &mut Scene, label: &str, on_click: impl Fn(&mut EventCtx) + 'static) {
     s.row(())
         .w(Len::Fill)
-        .h_px(t::SP_9)
+        .h_px(ITEM_H)
         .pad_xy(t::SP_3, t::SP_0)
         .align(Align::Center)
         .radius(t::R_SM)
@@ -102,3 +160,45 @@ fn item(s: &mut Scene, label: &str, on_click: impl Fn(&mut opal_gfx::EventCtx) +
             r.text((), label, t::TEXT_SM).color(t::TEXT);
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: [f32; 2] = [1280.0, 800.0];
+
+    #[test]
+    fn menu_height_counts_rows_gaps_and_chrome() {
+        let chrome = 2.0 * (PAD + BORDER_W);
+        assert_eq!(menu_height(0), chrome);
+        assert_eq!(menu_height(1), ITEM_H + chrome);
+        assert_eq!(menu_height(3), 3.0 * ITEM_H + 2.0 * GAP + chrome);
+    }
+
+    #[test]
+    fn opens_at_the_cursor_mid_window() {
+        let size = [MENU_W, menu_height(4)];
+        assert_eq!(fit_to_window([400.0, 300.0], size, WINDOW), [400.0, 300.0]);
+    }
+
+    #[test]
+    fn flips_left_at_the_right_edge() {
+        let size = [MENU_W, menu_height(4)];
+        let pos = fit_to_window([1200.0, 300.0], size, WINDOW);
+        assert_eq!(pos, [1200.0 - MENU_W, 300.0]);
+    }
+
+    #[test]
+    fn flips_up_at_the_bottom_edge() {
+        let size = [MENU_W, menu_height(4)];
+        let pos = fit_to_window([400.0, 780.0], size, WINDOW);
+        assert_eq!(pos, [400.0, 780.0 - size[1]]);
+    }
+
+    #[test]
+    fn clamps_to_the_corner_when_neither_side_fits() {
+        // Taller and wider than the room on either side of the cursor.
+        let pos = fit_to_window([150.0, 100.0], [MENU_W, 180.0], [300.0, 200.0]);
+        assert_eq!(pos, [0.0, 0.0]);
+    }
+}
@@ -9,6 +9,7 @@ use std::rc::Rc;
 
 use opal_gfx::{Align, Len, Scene, Signal};
 
+use crate::i18n::{tr, tr_n};
 use crate::model::DevicesModel;
 use crate::widgets::component::Component;
 use crate::widgets::icon::{Icon, IconSet};
@@ -40,10 +41,10 @@ impl Component for DevicesPanel<'_> {
                 .radius(t::R_LG)
                 .border(1.0, t::BORDER)
                 .child(move |panel| {
-                    panel.text((), "Connect to a device", 18.0).color(t::TEXT);
+                    panel.text((), tr("devices.title"), 18.0).color(t::TEXT);
                     if list.is_empty() {
                         panel.row(()).w(Len::Fill).h_px(t::SP_12).center().child(|e| {
-                            e.text((), "No devices found", 13.0).color(t::TEXT_DIM);
+                            e.text((), tr("devices.none"), 13.0).color(t::TEXT_DIM);
                         });
                     }
                     for d in &list {
@@ -120,8 +121,8 @@ fn device_row(
             .child(|m| {
                 m.text((), &name, 14.0).color(t::TEXT).max_width_px(260.0);
                 let sub = match (active, is_self) {
-                    (true, _) => "Playing".to_string(),
-                    (false, true) => format!("{kind} \u{2022} This device"),
+                    (true, _) => tr("devices.playing"),
+                    (false, true) => tr_n("devices.this_device", &kind),
                     (false, false) => kind.clone(),
                 };
                 m.text((), &sub, 12.0).color(t::TEXT_DIM);
@@ -12,10 +12,13 @@ use std::rc::Rc;
 
 use opal_gfx::{Align, Len, Scene, Signal};
 
+use crate::i18n::tr;
 use crate::model::MembershipModel;
 use crate::widgets::component::Component;
-use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::icon::IconSet;
+use crate::widgets::spinner::{Spin, progress_ring, spinner};
 use crate::widgets::tokens as t;
+use crate::widgets::toggle::checkbox;
 
 /// Fixed height of the scrolling playlist list — tall enough to show ~7
 /// rows before scrolling, matching the picker's popup feel.
@@ -27,6 +30,9 @@ pub struct LikeMenu<'a> {
     pub liked: &'a Signal<bool>,
     pub accent: &'a Signal<[f32; 4]>,
     pub icons: &'a Rc<IconSet>,
+    /// Clock for the loading spinner (before the index scan reports
+    /// progress; the ring takes over from there).
+    pub spin: &'a Spin,
     /// Add/remove the current track from playlist `id` (`add` = check on).
     pub on_toggle_playlist: Rc<dyn Fn(String, bool)>,
     /// Save/unsave the current track to Liked Songs (`add` = check on).
@@ -36,9 +42,12 @@ pub struct LikeMenu<'a> {
 impl Component for LikeMenu<'_> {
     fn view(&self, s: &mut Scene) {
         let icons = self.icons;
+        let spin = self.spin;
         let accent = self.accent.clone();
         let playlists = self.membership.playlists.borrow().clone();
         let ready = self.membership.ready.get();
+        let scan = self.membership.scan.clone();
+        let scanning = scan.get() > 0.0;
         let target = self.membership.target.borrow().clone();
         let liked_now = self.liked.get();
         // Pre-read membership for each playlist row (static read + rebuild on
@@ -59,7 +68,7 @@ impl Component for LikeMenu<'_> {
                 .border(1.0, t::BORDER)
                 .child(move |panel| {
                     // Header: "Add to playlist" + the track it acts on.
-                    panel.text((), "Add to playlist", 18.0).color(t::TEXT);
+                    panel.text((), tr("picker.title"), 18.0).color(t::TEXT);
                     if !target.name.is_empty() {
                         panel
                             .text((), format!("{} \u{2022} {}", target.name, target.artist), 12.0)
@@ -67,9 +76,20 @@ impl Component for LikeMenu<'_> {
                             .max_width_px(t::SP_80 - t::SP_10);
                     }
                     if !ready {
-                        panel.row(()).w(Len::Fill).h_px(t::SP_12).center().child(|e| {
-                            e.text((), "Loading playlists\u{2026}", 13.0).color(t::TEXT_DIM);
-                        });
+                        panel
+                            .row(())
+                            .w(Len::Fill)
+                            .h_px(t::SP_12)
+                            .center()
+                            .gap(t::SP_2)
+                            .child(|e| {
+                                if scanning {
+                                    progress_ring(e, &scan, t::ICON_SM, t::TEXT_DIM);
+                                } else {
+                                    spinner(e, spin, t::ICON_SM, t::TEXT_DIM);
+                                }
+                                e.text((), tr("picker.loading"), 13.0).color(t::TEXT_DIM);
+                            });
                         return;
                     }
                     // One scroll list: Liked Songs first (so it scrolls with
@@ -85,7 +105,8 @@ impl Component for LikeMenu<'_> {
                         .scrollbar(|sb| sb.auto_hide(true).margin(t::SP_0_5).thickness(t::SP_1))
                         .child(move |list| {
                             let liked_next = !liked_now;
-                            check_row(list, icons, "Liked Songs", liked_now, &accent, move || {
+                            let liked_songs = tr("player.liked_songs");
+                            check_row(list, icons, &liked_songs, liked_now, &accent, move || {
                                 on_toggle_liked(liked_next)
                             });
                             for (p, on) in playlists.iter().zip(in_set) {
@@ -127,21 +148,7 @@ fn check_row(
         .child(move |r| {
             r.text((), &name, 14.0).color(t::TEXT).max_width_px(t::SP_56);
             // Checkbox pushed to the trailing edge.
-            let mut box_node = r.row(());
-            box_node
-                .push_end()
-                .w_px(t::SP_5)
-                .h_px(t::SP_5)
-                .center()
-                .radius(t::R_SM);
-            if on {
-                box_node.color(accent.clone());
-                box_node.child(|b| {
-                    icons.render(b, Icon::Check, t::ICON_SM, t::PANEL);
-                });
-            } else {
-                box_node.border(1.5, t::TEXT_DIM);
-            }
+            r.row(()).push_end().child(|end| checkbox(end, icons, on, &accent, false));
         });
 }
 
@@ -0,0 +1,151 @@
+//! Lyrics page rendered into the centre pane — the playing track's lyrics
+//! from LRCLIB (see [`crate::lyrics`]).
+//!
+//! Synced lyrics highlight the line playing now through a colour bind on
+//! [`LyricsModel::active`] (no rebuild per line), and the frame loop
+//! springs the scroller to keep it in view ([`scroll_target`]). Clicking a
+//! synced line seeks there. Plain lyrics render as static text.
+//!
+//! The header names the source. While lookup is off (the default) the
+//! page says what turning it on would send, with a button to opt in.
+
+use std::rc::Rc;
+
+use opal_gfx::{Align, Computed, CursorIcon, Len, Scene};
+
+use crate::i18n::tr;
+use crate::model::LyricsModel;
+use crate::model::lyrics::LyricsState;
+use crate::views::MainNav;
+use crate::views::home::NavFn;
+use crate::widgets::button::{ButtonTone, pill_button};
+use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::tokens as t;
+
+/// Scroller node name — the frame loop looks it up to auto-scroll.
+pub const SCROLL_NODE: &str = "lyrics_scroll";
+
+/// Header block (back button + title) above the first line. The frame
+/// loop reads its laid-out height rather than re-deriving it.
+pub const HEAD_NODE: &str = "lyrics_head";
+
+/// Fixed line pitch, so a line's offset is `index × LINE_H` into the list.
+const LINE_H: f32 = t::SP_10;
+
+/// Scroller's top/bottom pad and the gap between header and lines.
+const PAD_Y: f32 = t::SP_2;
+const GAP: f32 = t::SP_3;
+
+/// Text size for a lyric line.
+const LINE_TEXT: f32 = 22.0;
+
+/// Longest a line may run before it's cut with an ellipsis — one row must
+/// stay one `LINE_H` tall or every line below it is placed wrong.
+const LINE_MAX_W: f32 = 640.0;
+
+/// Lines that already passed (vs upcoming) fade further back.
+const PAST: [f32; 4] = [t::TEXT_DIM[0], t::TEXT_DIM[1], t::TEXT_DIM[2], 0.55];
+
+/// Render the lyrics page into `s` (the caller's transition wrapper).
+/// `title` is the playing track's name; `on_seek(ms)` jumps playback;
+/// `on_lookup` persists [`LyricsModel::lookup`] after the opt-in button
+/// flips it (the same path as the settings switch).
+pub fn view(
+    s: &mut Scene,
+    icons: &Rc<IconSet>,
+    lyrics: &LyricsModel,
+    title: &str,
+    on_navigate: NavFn,
+    on_seek: Rc<dyn Fn(u64)>,
+    on_lookup: Rc<dyn Fn()>,
+) {
+    s.col(SCROLL_NODE)
+        .w(Len::Fill)
+        .h(Len::Fill)
+        .pad_xy(t::SP_6, PAD_Y)
+        .gap(GAP)
+        .scroll_y()
+        .layer()
+        .scrollbar(|sb| sb.auto_hide(true).margin(t::SP_0_5).thickness(t::SP_1))
+        .child(move |c| {
+            c.col(HEAD_NODE).w(Len::Fill).gap(GAP).child(|h| {
+                h.row(())
+                    .w_px(t::TOPBAR_BTN)
+                    .h_px(t::TOPBAR_BTN)
+                    .rgba(0.0, 0.0, 0.0, 0.30)
+                    .hover_color(t::PANEL_HI)
+                    .radius(t::R_FULL)
+                    .center()
+                    .on_click(move |ctx| on_navigate(ctx, MainNav::Home))
+                    .child(|b| icons.render(b, Icon::ChevronLeft, t::ICON_MD, t::TEXT));
+                h.row(()).w(Len::Fill).h_px(t::SP_10).align(Align::Center).child(|r| {
+                    r.text((), title, 28.0).color(t::TEXT).max_width_px(520.0);
+                });
+                h.text((), tr("lyrics.source"), t::TEXT_XS)
+                    .color(t::TEXT_DIM);
+            });
+            match &*lyrics.state.borrow() {
+                LyricsState::Off => {
+                    notice(c, &tr("lyrics.consent"));
+                    c.row(()).w(Len::Fill).center().child(|r| {
+                        let lookup = lyrics.lookup.clone();
+                        let on_lookup = on_lookup.clone();
+                        let tone = ButtonTone::Neutral;
+                        pill_button(r, icons, &tr("lyrics.turn_on"), None, tone, move |_| {
+                            lookup.set(true);
+                            on_lookup();
+                        });
+                    });
+                }
+                LyricsState::Loading => notice(c, &tr("lyrics.loading")),
+                LyricsState::Missing => notice(c, &tr("lyrics.missing")),
+                LyricsState::Failed => notice(c, &tr("lyrics.failed")),
+                LyricsState::Ready(l) => {
+                    // Lines sit flush (no column gap) so the pitch is exact.
+                    c.col(()).w(Len::Fill).child(|list| {
+                        for (i, line) in l.lines.iter().enumerate() {
+                            let mut row = list.row(());
+                            row.w(Len::Fill).h_px(LINE_H).align(Align::Center);
+                            if l.synced {
+                                let at = line.at_ms;
+                                let on_seek = on_seek.clone();
+                                row.cursor(CursorIcon::Pointer)
+                                    .hover_opacity(0.8)
+                                    .on_click(move |_| on_seek(at));
+                            }
+                            let tint = Computed::new((lyrics.active.clone(),), move |(a,)| {
+                                match a {
+                                    Some(a) if a == i => t::TEXT,
+                                    Some(a) if a > i => PAST,
+                                    _ => t::TEXT_DIM,
+                                }
+                            });
+                            // An empty line is an instrumental break: keep
+                            // its slot (timing) but show a quiet marker.
+                            let text = match line.text.as_str() {
+                                "" => "\u{266a}",
+                                text => text,
+                            };
+                            row.child(|r| {
+                                r.text((), text, LINE_TEXT).color(tint).max_width_px(LINE_MAX_W);
+                            });
+                        }
+                    });
+                }
+            }
+        });
+}
+
+fn notice(s: &mut Scene, msg: &str) {
+    s.row(()).w(Len::Fill).h_px(t::SP_14).center().child(|e| {
+        e.text((), msg, 14.0).color(t::TEXT_DIM);
+    });
+}
+
+/// Logical-px scroll offset that puts line `index` about a third of the
+/// way down a `viewport_h`-tall scroller — reading position, with a few
+/// upcoming lines visible below it. `head_h` is the measured height of
+/// [`HEAD_NODE`].
+pub fn scroll_target(index: usize, viewport_h: f32, head_h: f32) -> f32 {
+    (PAD_Y + head_h + GAP + index as f32 * LINE_H - viewport_h / 3.0).max(0.0)
+}
@@ -14,6 +14,7 @@ use opal_gfx::{
 
 use crate::album_art;
 use crate::api::{AlbumRef, HomeData};
+use crate::i18n::{tr, tr_n};
 use crate::model::ArtModel;
 use crate::api::PlayTarget;
 use crate::views::home::playlist::{self, PlaylistViewData};
@@ -23,11 +24,17 @@ use crate::widgets::chip::chip;
 use crate::widgets::color::accent_fg;
 use crate::widgets::component::Component;
 use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::motion;
 use crate::widgets::thumb::thumb;
 use crate::widgets::tokens as t;
 
-/// Content filter tabs shown across the top of the pane.
-const FILTERS: &[&str] = &["All", "Music", "Podcasts", "Audiobooks"];
+/// Content filter tabs shown across the top of the pane (label keys).
+const FILTERS: &[&str] = &[
+    "home.filter.all",
+    "home.filter.music",
+    "home.filter.podcasts",
+    "home.filter.audiobooks",
+];
 
 /// Max tiles rendered per home row. The row is a horizontal scroller, so
 /// this is sized to overflow the widest screen (≈12 × ~190px ≈ 2280px) —
@@ -48,7 +55,8 @@ pub struct MainPane<'a> {
     pub playlist: Option<&'a PlaylistViewData>,
     /// View data for the open artist page (`Some` when `nav` is an Artist).
     pub artist: Option<&'a crate::views::home::artist::ArtistViewData>,
-    /// View data for a "Show all" list (`Some` when `nav` is ShowAll).
+    /// View data for a "Show all" list (`Some` when `nav` is ShowAll or
+    /// Search — results render as the same list).
     pub show_all: Option<&'a crate::views::home::show_all::ShowAllViewData>,
     /// The active device's queue (`None` while loading; `nav` is Queue).
     pub queue: Option<&'a [crate::api::PlaylistTrack]>,
@@ -56,6 +64,13 @@ pub struct MainPane<'a> {
     pub pulse: &'a Signal<f32>,
     /// Skip forward N tracks — clicking a queue row jumps to it.
     pub on_skip: Rc<dyn Fn(u32)>,
+    /// Lyrics page state + the playing track's title (`nav` is Lyrics).
+    pub lyrics: &'a crate::model::LyricsModel,
+    pub lyrics_title: &'a str,
+    /// Persist the lyrics-lookup opt-in (the lyrics page's button).
+    pub on_lyrics_lookup: Rc<dyn Fn()>,
+    /// Seek playback to a position (ms) — clicking a synced lyric line.
+    pub on_seek: Rc<dyn Fn(u64)>,
     /// Right-click a track row → open the context menu.
     pub on_context_menu: crate::views::home::CtxMenuFn,
     /// 0 → 1 entrance transition progress on nav change.
@@ -133,6 +148,31 @@ impl Component for MainPane<'_> {
                                 );
                             }
                         }
+                        MainNav::Lyrics => {
+                            crate::views::home::lyrics::view(
+                                content,
+                                self.icons,
+                                self.lyrics,
+                                self.lyrics_title,
+                                self.on_navigate.clone(),
+                                self.on_seek.clone(),
+                                self.on_lyrics_lookup.clone(),
+                            );
+                        }
+                        MainNav::Search => {
+                            // Search results reuse the Show-all list page.
+                            if let Some(sv) = self.show_all {
+                                crate::views::home::show_all::view(
+                                    content,
+                                    self.icons,
+                                    sv,
+                                    "search_scroll",
+                                    self.on_navigate.clone(),
+                                    self.on_play.clone(),
+                                    self.on_context_menu.clone(),
+                                );
+                            }
+                        }
                         MainNav::Queue => {
                             crate::views::home::queue::view(
                                 content,
@@ -160,12 +200,12 @@ impl MainPane<'_> {
         let ctx_menu = self.on_context_menu.clone();
         let on_play = self.on_play.clone();
         let greeting = match home.profile.as_ref() {
-            Some(p) if !p.display_name.is_empty() => format!("Good evening, {}", p.display_name),
-            _ => "Good evening".to_string(),
+            Some(p) if !p.display_name.is_empty() => tr_n("home.greeting.named", &p.display_name),
+            _ => tr("home.greeting"),
         };
         let made_for = match home.profile.as_ref() {
-            Some(p) if !p.display_name.is_empty() => format!("Made For {}", p.display_name),
-            _ => "Made For You".to_string(),
+            Some(p) if !p.display_name.is_empty() => tr_n("home.made_for", &p.display_name),
+            _ => tr("home.made_for_you"),
         };
         // Filter chips pinned at the top of the pane.
         content
@@ -176,8 +216,8 @@ impl MainPane<'_> {
             .gap(t::SP_2)
             .align(Align::Center)
             .child(|chips| {
-                for (i, label) in FILTERS.iter().enumerate() {
-                    chip(chips, label, i == 0, accent);
+                for (i, key) in FILTERS.iter().enumerate() {
+                    chip(chips, &tr(key), i == 0, accent);
                 }
             });
         // Scrolling content body — all sections hit real endpoints.
@@ -201,13 +241,14 @@ impl MainPane<'_> {
 
                 // Spotlit new release (newest album from #1 top artist).
                 if let Some(rel) = home.latest_release.as_ref() {
-                    section_header(c, &format!("New release from {}", rel.artist), None, &nav);
+                    let title = tr_n("home.new_release", &rel.artist);
+                    section_header(c, &title, None, &nav);
                     new_release_card(c, icons, rel, art, accent, nav.clone());
                 }
 
                 section_header(
                     c,
-                    "Recently played",
+                    &tr("home.recent"),
                     Some(MainNav::ShowAll {
                         section: HomeSection::Recent,
                     }),
@@ -233,6 +274,7 @@ impl MainPane<'_> {
                                 uri: format!("spotify:track:{}", t.id),
                                 album_id: t.album_id.clone(),
                                 artist_id: String::new(),
+                                ..Default::default()
                             }),
                         )
                     },
@@ -240,7 +282,7 @@ impl MainPane<'_> {
 
                 section_header(
                     c,
-                    "Your top artists",
+                    &tr("home.top_artists"),
                     Some(MainNav::ShowAll {
                         section: HomeSection::TopArtists,
                     }),
@@ -257,7 +299,7 @@ impl MainPane<'_> {
                     |a| {
                         (
                             a.name.clone(),
-                            "Artist".to_string(),
+                            tr("kind.artist"),
                             a.image_url.clone(),
                             Some(MainNav::Artist { id: a.id.clone() }),
                             None,
@@ -267,7 +309,7 @@ impl MainPane<'_> {
 
                 section_header(
                     c,
-                    "Your top tracks",
+                    &tr("home.top_tracks"),
                     Some(MainNav::ShowAll {
                         section: HomeSection::TopTracks,
                     }),
@@ -293,6 +335,7 @@ impl MainPane<'_> {
                                 uri: format!("spotify:track:{}", t.id),
                                 album_id: t.album_id.clone(),
                                 artist_id: String::new(),
+                                ..Default::default()
                             }),
                         )
                     },
@@ -317,7 +360,7 @@ impl MainPane<'_> {
                     |p| {
                         (
                             p.name.clone(),
-                            "Playlist".to_string(),
+                            tr("kind.playlist"),
                             p.image_url.clone(),
                             Some(MainNav::Playlist {
                                 id: p.id.clone(),
@@ -404,7 +447,7 @@ pub(crate) fn card_row(
     let arrows_vis = animated(
         Computed::new((hovered.clone(),), |(h,)| if h { 1.0 } else { 0.0 }),
         Curve::EaseInOut,
-        Duration::from_millis(160),
+        motion::dur(Duration::from_millis(160)),
     );
     s.col(())
         .w(Len::Fill)
@@ -508,7 +551,7 @@ fn section_header(s: &mut Scene, title: &str, show_all: Option<MainNav>, nav: &N
                     .hover_opacity(0.7)
                     .on_click(move |ctx| nav(ctx, target.clone()))
                     .child(|r| {
-                        r.text((), "Show all", 12.0).color(t::TEXT_DIM);
+                        r.text((), tr("home.show_all"), 12.0).color(t::TEXT_DIM);
                     });
             }
         });
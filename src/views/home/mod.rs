@@ -7,6 +7,7 @@ pub mod artist;
 pub mod context_menu;
 pub mod devices;
 pub mod like_menu;
+pub mod lyrics;
 pub mod main_pane;
 pub mod now_playing;
 pub mod player_bar;
@@ -15,6 +16,7 @@ pub mod queue;
 pub mod settings;
 pub mod show_all;
 pub mod sidebar;
+pub mod toasts;
 pub mod top_bar;
 
 use std::cell::Cell;
@@ -22,16 +24,21 @@ use std::rc::Rc;
 use std::sync::Arc;
 use std::time::Instant;
 
-use opal_gfx::{Computed, EventCtx, ImageHandle, Len, Scene, Signal, WakeHandle};
+use opal_gfx::{Computed, EventCtx, ImageHandle, Key, Len, Scene, Signal, WakeHandle};
 
 use crate::album_art;
 use crate::api::PlayTarget;
 use crate::app::AppState;
 use crate::app::cx::Cx;
+use crate::i18n::{tr, tr_n};
+use crate::model::player::{ActionSource, PlayerAction, PlayerIntent};
+use crate::model::prefs::Panel;
+use crate::model::toast::{ERROR_DURATION, ToastKind};
 use crate::views::{HomeSection, MainNav, View};
 use crate::widgets::component::Component;
 use crate::widgets::crossfade::OPAQUE_TINT;
 use crate::widgets::icon::IconSet;
+use crate::widgets::surface;
 use crate::widgets::tokens as t;
 use crate::worker::{PlaybackCmd, Worker};
 
@@ -63,18 +70,75 @@ pub fn attach_context_menu(
     row.on_right_click(move |ctx| menu(ctx, target.clone()));
 }
 
-/// A transport intent raised by a player-bar button click. The consumer
-/// (main.rs) maps these to optimistic signal flips + worker commands;
-/// the UI layer stays ignorant of tokens and the Web API.
-#[derive(Debug, Clone, Copy)]
-pub enum PlayerAction {
-    PlayPause,
-    Next,
-    Prev,
-    ToggleShuffle,
-    CycleRepeat,
-    /// Commit the volume slider's released position (0..=100).
-    SetVolume(u8),
+/// Transport dispatcher: optimistic model flip + the worker command.
+/// Shared by the player-bar buttons and the OS media keys, so both paths
+/// behave identically once an intent is admitted. Conflict policy:
+/// an explicit Play/Pause that matches the current state is dropped.
+/// Media-key repeats never get here — the frame tick filters them first
+/// (see [`crate::model::player::PlayerModel::admit_media_key`]).
+pub fn dispatch_player_action(state: &AppState, worker: &Worker, intent: PlayerIntent) {
+    let PlayerIntent { action, source } = intent;
+    let playing = state.player_ui.is_playing.get();
+    let stale = match action {
+        PlayerAction::Play => playing,
+        PlayerAction::Pause => !playing,
+        _ => false,
+    };
+    if stale {
+        log::debug!("player action {action:?} from {source:?} dropped");
+        return;
+    }
+    log::debug!("player action {action:?} from {source:?}");
+    let Some(token) = state.auth.token() else {
+        log::warn!("playback action ignored — no auth token");
+        return;
+    };
+    let cmd = match action {
+        PlayerAction::PlayPause | PlayerAction::Play | PlayerAction::Pause => {
+            let was_playing = state.player_ui.toggle_play();
+            let local = state.devices.playing_on_self.get();
+            if was_playing {
+                worker.playback(token, PlaybackCmd::Pause, local);
+                return;
+            }
+            // Resume. On cold start nothing is actually playing on any device
+            // (no live push yet — the snapshot is just the persisted seed), so
+            // a bare Web API resume 404s / no-ops — start the last-played
+            // track at exactly the position the chrome already shows, so the
+            // play button matches the displayed progress instead of
+            // restarting (and without the user first picking a song).
+            if !state.player_ui.live.get() {
+                let last = state.prefs.data.borrow().last_player.as_ref().map(|p| {
+                    (p.track_id.clone(), p.progress_ms as u32, p.context_uri.clone())
+                });
+                if let Some((uri, position_ms, context_uri)) = last {
+                    // The worker fills a missing context with the track's
+                    // album so playback continues past it.
+                    worker.playback(
+                        token,
+                        PlaybackCmd::PlayContext(crate::api::PlayTarget::Resume {
+                            uri,
+                            position_ms,
+                            context_uri,
+                        }),
+                        false,
+                    );
+                    return;
+                }
+            }
+            worker.playback(token, PlaybackCmd::Play, local);
+            return;
+        }
+        PlayerAction::Next => PlaybackCmd::Next,
+        PlayerAction::Prev => PlaybackCmd::Prev,
+        PlayerAction::ToggleShuffle => PlaybackCmd::Shuffle(state.player_ui.toggle_shuffle()),
+        PlayerAction::CycleRepeat => PlaybackCmd::Repeat(state.player_ui.cycle_repeat()),
+        PlayerAction::SetVolume(pct) => PlaybackCmd::Volume(pct),
+    };
+    // Drive our own Spirc directly when Opal is the active device (instant
+    // + reliable; the Web API relay to self can go stale after long uptime).
+    let local = state.devices.playing_on_self.get();
+    worker.playback(token, cmd, local);
 }
 
 /// Per-build layout inputs — the ambient backdrop signals + splitter
@@ -93,7 +157,7 @@ struct Layout<'a> {
     pub now_playing_w: &'a Signal<f32>,
     /// Called by the splitters after every committed width change.
     /// Wired by the consumer to debounced prefs persistence.
-    pub mark_dirty: std::rc::Rc<dyn Fn()>,
+    pub on_resize: std::rc::Rc<dyn Fn(Panel)>,
     /// The now-playing pane, a self-rendering [`Component`] reading its
     /// own backdrop/player/canvas slices.
     pub now_playing: &'a crate::views::home::now_playing::NowPlaying<'a>,
@@ -115,7 +179,15 @@ struct Layout<'a> {
     pub menu: &'a crate::model::MenuModel,
     pub on_menu_add_queue: Rc<dyn Fn(String)>,
     pub on_menu_navigate: NavFn,
+    pub on_menu_copy_link: Rc<dyn Fn(&mut EventCtx, String)>,
+    pub on_menu_toggle_warm: Rc<dyn Fn(String)>,
     pub on_menu_close: Rc<dyn Fn()>,
+    /// Toast stack + its close button.
+    pub toasts: &'a crate::model::ToastModel,
+    pub on_toast_dismiss: Rc<dyn Fn(u64)>,
+    pub icons: &'a IconSet,
+    /// Current accent, tinting the solid background under reduced effects.
+    pub accent: &'a Signal<[f32; 4]>,
 }
 
 fn render(s: &mut Scene, v: &Layout) {
@@ -124,73 +196,72 @@ fn render(s: &mut Scene, v: &Layout) {
     // `home_bg` fill below, which `main.rs` hides once the opaque album-art
     // backdrop fully covers it (no wasted full-screen draw behind the art).
     s.col("home_root").fill().child(|root| {
-        // Base background fill. Toggled off (→ no instance, no layer) by
-        // `tick_canvas_dim`/the backdrop watcher once the art covers it.
-        root.rect("home_bg")
-            .abs(0.0, 0.0)
-            .w(Len::Fill)
-            .h(Len::Fill)
-            .rgba(t::BG[0], t::BG[1], t::BG[2], 1.0);
-        // Outgoing layer: previous cover, held fully opaque so the
-        // incoming layer dissolves over solid coverage (no background
-        // bleed at the midpoint — see `fade_in_alpha`). Bound to the
-        // signal via `image_bound`, so `promote_backdrop` swaps the
-        // handle with no scene rebuild; `None` renders nothing (the
-        // first track has no previous cover).
-        // Gate the outgoing layer to `None` once the crossfade settles
-        // (`crossfade_t == 1`): the incoming cover is then fully opaque
-        // and covers it, so drawing it is a wasted per-frame draw call.
-        let backdrop_prev_gated = Computed::new(
-            (v.backdrop_prev.clone(), v.crossfade_t.clone()),
-            |(p, t)| if t >= 1.0 { None } else { p },
-        );
-        root.image_bound((), backdrop_prev_gated)
-            .abs(0.0, 0.0)
-            .w(Len::Fill)
-            .h(Len::Fill)
-            .image_cover()
-            .blur_source()
-            .color(OPAQUE_TINT);
-        // Incoming layer: current cover, fading in over the outgoing
-        // one. **Composite-opacity crossfade (compositor P4):** the
-        // image is held opaque (`OPAQUE_TINT`) and promoted to its own
-        // layer via `.layer_opacity(crossfade_t)` — the lib drives the
-        // layer's *composite* opacity from the tween each frame, so the
-        // incoming cover's texture rasters **once** and the fade is a
-        // composite-only recomposite (no per-frame image re-raster).
-        // Generic glass (P4) sources its backdrop from the composite of
-        // the layers below it, so the glass still blurs the dissolving
-        // result. `blur_source` keeps the (still per-frame, inherent)
-        // backdrop blur firing while the composite changes.
-        root.image_bound((), v.backdrop_curr.clone())
-            .abs(0.0, 0.0)
-            .w(Len::Fill)
-            .h(Len::Fill)
-            .image_cover()
-            .blur_source()
-            .layer_opacity(v.crossfade_t.clone())
-            .color(OPAQUE_TINT);
-        // Frosted-glass overlay: heavy blur + dark tint = the dimmed
-        // ambient look. Always present in Home — before any art it
-        // just blurs the dark BG (reads the same), and keeping it
-        // unconditional means the first cover appears *under* the
-        // glass without needing a rebuild to introduce it.
-        // The tint adapts to the cover's brightness: a near-white cover
-        // would otherwise lift the whole backdrop to mid-grey and wash
-        // out every icon/label above it, so bright art gets a
-        // proportionally deeper dim — the chrome's background stays
-        // predictably dark, which is what the contrast-lifted accent is
-        // calibrated against. Reactive colour bind riding the slow
-        // crossfade tween — re-tints once per track change.
-        let glass_tint = Computed::new((v.art_luma.clone(),), |(l,)| {
-            [0.0, 0.0, 0.0, 0.25 + 0.40 * l.clamp(0.0, 1.0)]
+        // Base background fill + the ambient stack over it. The fill is
+        // toggled off (→ no instance, no layer) by `tick_canvas_dim`/the
+        // backdrop watcher once the art covers it. Reduced effects (safe
+        // mode / low-power) skip the stack — see `surface::ambient`.
+        surface::ambient(root, "home_bg", v.accent, |root| {
+            // Outgoing layer: previous cover, held fully opaque so the
+            // incoming layer dissolves over solid coverage (no background
+            // bleed at the midpoint — see `fade_in_alpha`). Bound to the
+            // signal via `image_bound`, so `promote_backdrop` swaps the
+            // handle with no scene rebuild; `None` renders nothing (the
+            // first track has no previous cover).
+            // Gate the outgoing layer to `None` once the crossfade settles
+            // (`crossfade_t == 1`): the incoming cover is then fully opaque
+            // and covers it, so drawing it is a wasted per-frame draw call.
+            let backdrop_prev_gated = Computed::new(
+                (v.backdrop_prev.clone(), v.crossfade_t.clone()),
+                |(p, t)| if t >= 1.0 { None } else { p },
+            );
+            root.image_bound((), backdrop_prev_gated)
+                .abs(0.0, 0.0)
+                .w(Len::Fill)
+                .h(Len::Fill)
+                .image_cover()
+                .blur_source()
+                .color(OPAQUE_TINT);
+            // Incoming layer: current cover, fading in over the outgoing
+            // one. **Composite-opacity crossfade (compositor P4):** the
+            // image is held opaque (`OPAQUE_TINT`) and promoted to its own
+            // layer via `.layer_opacity(crossfade_t)` — the lib drives the
+            // layer's *composite* opacity from the tween each frame, so the
+            // incoming cover's texture rasters **once** and the fade is a
+            // composite-only recomposite (no per-frame image re-raster).
+            // Generic glass (P4) sources its backdrop from the composite of
+            // the layers below it, so the glass still blurs the dissolving
+            // result. `blur_source` keeps the (still per-frame, inherent)
+            // backdrop blur firing while the composite changes.
+            root.image_bound((), v.backdrop_curr.clone())
+                .abs(0.0, 0.0)
+                .w(Len::Fill)
+                .h(Len::Fill)
+                .image_cover()
+                .blur_source()
+                .layer_opacity(v.crossfade_t.clone())
+                .color(OPAQUE_TINT);
+            // Frosted-glass overlay: heavy blur + dark tint = the dimmed
+            // ambient look. Always present in Home (unless reduced) —
+            // before any art it just blurs the dark BG (reads the same),
+            // and keeping it unconditional means the first cover appears
+            // *under* the glass without needing a rebuild to introduce it.
+            // The tint adapts to the cover's brightness: a near-white cover
+            // would otherwise lift the whole backdrop to mid-grey and wash
+            // out every icon/label above it, so bright art gets a
+            // proportionally deeper dim — the chrome's background stays
+            // predictably dark, which is what the contrast-lifted accent is
+            // calibrated against. Reactive colour bind riding the slow
+            // crossfade tween — re-tints once per track change.
+            let glass_tint = Computed::new((v.art_luma.clone(),), |(l,)| {
+                [0.0, 0.0, 0.0, 0.25 + 0.40 * l.clamp(0.0, 1.0)]
+            });
+            root.glass(())
+                .abs(0.0, 0.0)
+                .w(Len::Fill)
+                .h(Len::Fill)
+                .blur(80.0)
+                .color(glass_tint);
         });
-        root.glass(())
-            .abs(0.0, 0.0)
-            .w(Len::Fill)
-            .h(Len::Fill)
-            .blur(80.0)
-            .color(glass_tint);
         v.top_bar.view(root);
         root.row(())
             .w(Len::Fill)
@@ -208,7 +279,10 @@ fn render(s: &mut Scene, v: &Layout) {
                         min: t::SIDEBAR_MIN,
                         max: t::SIDEBAR_MAX,
                         collapsed: t::SIDEBAR_COLLAPSED,
-                        on_change: v.mark_dirty.clone(),
+                        on_change: {
+                            let on_resize = v.on_resize.clone();
+                            Rc::new(move || on_resize(Panel::Sidebar))
+                        },
                     },
                 );
                 v.main_pane.view(b);
@@ -221,7 +295,10 @@ fn render(s: &mut Scene, v: &Layout) {
                         min: t::NOW_PLAYING_MIN,
                         max: t::NOW_PLAYING_MAX,
                         collapsed: t::SP_0,
-                        on_change: v.mark_dirty.clone(),
+                        on_change: {
+                            let on_resize = v.on_resize.clone();
+                            Rc::new(move || on_resize(Panel::NowPlaying))
+                        },
                     },
                 );
                 v.now_playing.view(b);
@@ -232,12 +309,15 @@ fn render(s: &mut Scene, v: &Layout) {
         v.settings_panel.view(root);
         v.devices_panel.view(root);
         v.like_menu.view(root);
+        toasts::view(root, v.toasts, v.icons, v.accent, v.on_toast_dismiss.clone());
         // Right-click context menu — topmost; renders only when open.
         context_menu::view(
             root,
             v.menu,
             v.on_menu_add_queue.clone(),
             v.on_menu_navigate.clone(),
+            v.on_menu_copy_link.clone(),
+            v.on_menu_toggle_warm.clone(),
             v.on_menu_close.clone(),
         );
     });
@@ -254,23 +334,38 @@ pub struct HomeView {
     on_canvas_change: Rc<dyn Fn()>,
     sign_out: Rc<dyn Fn()>,
     on_settings_open: Rc<dyn Fn()>,
+    on_search: Rc<dyn Fn(&str)>,
+    on_search_submit: Rc<dyn Fn()>,
+    on_search_key: Rc<dyn Fn(Key) -> bool>,
     on_clear_cache: Rc<dyn Fn()>,
     on_change_cache_dir: Rc<dyn Fn()>,
     on_navigate: NavFn,
     on_play: PlayFn,
     request_cover: playlist::CoverFn,
-    mark_dirty: Rc<dyn Fn()>,
+    on_resize: Rc<dyn Fn(Panel)>,
     on_devices_open: Rc<dyn Fn()>,
     on_like_open: Rc<dyn Fn()>,
     on_like_toggle_playlist: Rc<dyn Fn(String, bool)>,
     on_like_toggle_liked: Rc<dyn Fn(bool)>,
     on_transfer: Rc<dyn Fn(String)>,
     on_quality: Rc<dyn Fn(crate::prefs::AudioQuality)>,
+    on_quality_toggle: Rc<dyn Fn()>,
+    on_output_device: Rc<dyn Fn(Option<String>)>,
+    on_output_device_toggle: Rc<dyn Fn()>,
+    on_locale: Rc<dyn Fn(String)>,
+    on_locale_toggle: Rc<dyn Fn()>,
     on_normalize: Rc<dyn Fn()>,
+    on_warmup: Rc<dyn Fn()>,
+    on_lyrics_lookup: Rc<dyn Fn()>,
+    on_effects: Rc<dyn Fn()>,
     on_skip: Rc<dyn Fn(u32)>,
+    on_seek: Rc<dyn Fn(u64)>,
     on_context_menu: CtxMenuFn,
     on_add_queue: Rc<dyn Fn(String)>,
+    on_toggle_warm: Rc<dyn Fn(String)>,
     on_menu_close: Rc<dyn Fn()>,
+    on_copy_link: Rc<dyn Fn(&mut EventCtx, String)>,
+    on_toast_dismiss: Rc<dyn Fn(u64)>,
 }
 
 impl HomeView {
@@ -290,62 +385,8 @@ impl HomeView {
             let state = state.clone();
             let worker = worker.clone();
             Rc::new(move |action| {
-                let Some(token) = state.auth.token() else {
-                    log::warn!("playback action ignored — no auth token");
-                    return;
-                };
-                let cmd = match action {
-                    PlayerAction::PlayPause => {
-                        let was_playing = state.player_ui.toggle_play();
-                        let local = state.devices.playing_on_self.get();
-                        if was_playing {
-                            worker.playback(token, PlaybackCmd::Pause, local);
-                            return;
-                        }
-                        // Resume. On cold start nothing is actually playing on
-                        // any device (no live push yet — the snapshot is just
-                        // the persisted seed), so a bare Web API resume 404s /
-                        // no-ops — start the last-played track at exactly the
-                        // position the chrome already shows, so the play button
-                        // matches the displayed progress instead of restarting
-                        // (and without the user first picking a song).
-                        if !state.player_ui.live.get() {
-                            let last = state.prefs.data.borrow().last_player.as_ref().map(|p| {
-                                (p.track_id.clone(), p.progress_ms as u32, p.context_uri.clone())
-                            });
-                            if let Some((uri, position_ms, context_uri)) = last {
-                                // The worker fills a missing context with the
-                                // track's album so playback continues past it.
-                                worker.playback(
-                                    token,
-                                    PlaybackCmd::PlayContext(crate::api::PlayTarget::Resume {
-                                        uri,
-                                        position_ms,
-                                        context_uri,
-                                    }),
-                                    false,
-                                );
-                                return;
-                            }
-                        }
-                        worker.playback(token, PlaybackCmd::Play, local);
-                        return;
-                    }
-                    PlayerAction::Next => PlaybackCmd::Next,
-                    PlayerAction::Prev => PlaybackCmd::Prev,
-                    PlayerAction::ToggleShuffle => {
-                        PlaybackCmd::Shuffle(state.player_ui.toggle_shuffle())
-                    }
-                    PlayerAction::CycleRepeat => {
-                        PlaybackCmd::Repeat(state.player_ui.cycle_repeat())
-                    }
-                    PlayerAction::SetVolume(pct) => PlaybackCmd::Volume(pct),
-                };
-                // Drive our own Spirc directly when Opal is the active
-                // device (instant + reliable; the Web API relay to self can
-                // go stale after long uptime).
-                let local = state.devices.playing_on_self.get();
-                worker.playback(token, cmd, local);
+                let source = ActionSource::UiButton;
+                dispatch_player_action(&state, &worker, PlayerIntent { action, source })
             })
         };
         let on_canvas_change: Rc<dyn Fn()> = {
@@ -353,6 +394,10 @@ impl HomeView {
             let worker = worker.clone();
             Rc::new(move || {
                 state.prefs.mark_dirty(Instant::now());
+                // Safe mode still persists the choice, but plays no video.
+                if !state.canvas_video() {
+                    return;
+                }
                 state
                     .canvas
                     .on_toggle(state.player_ui.snapshot.borrow().as_ref(), &worker);
@@ -373,9 +418,17 @@ impl HomeView {
         };
         let on_settings_open: Rc<dyn Fn()> = {
             let state = state.clone();
+            let worker = worker.clone();
             let rebuild = rebuild.clone();
             Rc::new(move || {
                 state.settings.refresh_usage();
+                state.settings.refresh_locales();
+                // Devices come and go while the app runs; the list lands
+                // (and rebuilds the modal) once the worker has probed.
+                worker.list_output_devices();
+                state.settings.quality_open.set(false);
+                state.settings.device_open.set(false);
+                state.settings.locale_open.set(false);
                 rebuild.set(true);
             })
         };
@@ -503,6 +556,68 @@ impl HomeView {
                 rebuild.set(true);
             })
         };
+        // Open/close the quality dropdown (a structural swap → rebuild).
+        let on_quality_toggle: Rc<dyn Fn()> = {
+            let state = state.clone();
+            let rebuild = rebuild.clone();
+            Rc::new(move || {
+                let open = &state.settings.quality_open;
+                open.set(!open.get());
+                state.settings.device_open.set(false);
+                state.settings.locale_open.set(false);
+                rebuild.set(true);
+            })
+        };
+        let on_output_device: Rc<dyn Fn(Option<String>)> = {
+            let state = state.clone();
+            let rebuild = rebuild.clone();
+            Rc::new(move |device| {
+                state.prefs.data.borrow_mut().audio.output_device = device;
+                state.prefs.mark_dirty(Instant::now());
+                rebuild.set(true);
+            })
+        };
+        // Same as the quality toggle; only one dropdown is open at a time.
+        let on_output_device_toggle: Rc<dyn Fn()> = {
+            let state = state.clone();
+            let rebuild = rebuild.clone();
+            Rc::new(move || {
+                let open = &state.settings.device_open;
+                open.set(!open.get());
+                state.settings.quality_open.set(false);
+                state.settings.locale_open.set(false);
+                rebuild.set(true);
+            })
+        };
+        // Language: applied live — the rebuild re-resolves every keyed
+        // string. A bundle that vanished since the list was read keeps the
+        // current language and says so.
+        let on_locale: Rc<dyn Fn(String)> = {
+            let state = state.clone();
+            let rebuild = rebuild.clone();
+            Rc::new(move |code| {
+                if !crate::i18n::set_locale(&code) {
+                    let msg = tr_n("toast.locale_missing", &code);
+                    state.toasts.show_for(msg, ToastKind::Error, ERROR_DURATION);
+                    return;
+                }
+                state.prefs.data.borrow_mut().locale = (code != "en").then_some(code);
+                state.prefs.mark_dirty(Instant::now());
+                state.settings.locale_open.set(false);
+                rebuild.set(true);
+            })
+        };
+        let on_locale_toggle: Rc<dyn Fn()> = {
+            let state = state.clone();
+            let rebuild = rebuild.clone();
+            Rc::new(move || {
+                let open = &state.settings.locale_open;
+                open.set(!open.get());
+                state.settings.quality_open.set(false);
+                state.settings.device_open.set(false);
+                rebuild.set(true);
+            })
+        };
         let on_normalize: Rc<dyn Fn()> = {
             let state = state.clone();
             Rc::new(move || {
@@ -513,6 +628,45 @@ impl HomeView {
                 state.prefs.mark_dirty(Instant::now());
             })
         };
+        let on_warmup: Rc<dyn Fn()> = {
+            let state = state.clone();
+            Rc::new(move || {
+                // Same as normalize: persisted, applied at the next launch.
+                let on = state.settings.warmup.get();
+                state.prefs.data.borrow_mut().warmup.enabled = on;
+                state.prefs.mark_dirty(Instant::now());
+            })
+        };
+        let on_lyrics_lookup: Rc<dyn Fn()> = {
+            let state = state.clone();
+            let worker = worker.clone();
+            let rebuild = rebuild.clone();
+            Rc::new(move || {
+                // Persisted and applied live: an open lyrics page fetches
+                // now (or drops back to the opt-in notice).
+                let on = state.lyrics.lookup.get();
+                state.prefs.data.borrow_mut().lyrics_lookup = on;
+                state.prefs.mark_dirty(Instant::now());
+                if *state.router.nav.borrow() == MainNav::Lyrics {
+                    if let Some(p) = state.player_ui.snapshot.borrow().as_ref() {
+                        state.lyrics.ensure(&worker, p);
+                    }
+                    rebuild.set(true);
+                }
+            })
+        };
+        let on_effects: Rc<dyn Fn()> = {
+            let state = state.clone();
+            Rc::new(move || {
+                // Persist both toggles; applied live — the frame tick sees
+                // the flipped signal and rebuilds with the other surfaces.
+                let mut prefs = state.prefs.data.borrow_mut();
+                prefs.effects.reduce = state.power.reduce.get();
+                prefs.effects.reduce_on_battery = state.power.reduce_on_battery.get();
+                drop(prefs);
+                state.prefs.mark_dirty(Instant::now());
+            })
+        };
         let on_skip: Rc<dyn Fn(u32)> = {
             let state = state.clone();
             let worker = worker.clone();
@@ -525,6 +679,17 @@ impl HomeView {
                 worker.skip_forward(token, count, local);
             })
         };
+        let on_seek: Rc<dyn Fn(u64)> = {
+            let state = state.clone();
+            let worker = worker.clone();
+            Rc::new(move |ms| {
+                let Some(token) = state.auth.token() else { return };
+                // Same path as a seek-bar release (`PlayerModel::tick_seek`).
+                let local = state.devices.playing_on_self.get();
+                let ms = u32::try_from(ms).unwrap_or(u32::MAX);
+                worker.playback(token, PlaybackCmd::Seek(ms), local);
+            })
+        };
         let on_context_menu: CtxMenuFn = {
             let state = state.clone();
             let rebuild = rebuild.clone();
@@ -545,6 +710,17 @@ impl HomeView {
                 }
             })
         };
+        // Mark/unmark a library playlist for launch warm-up. The menu
+        // closes (and rebuilds) right after, so the sidebar's next menu
+        // target picks up the new mark.
+        let on_toggle_warm: Rc<dyn Fn(String)> = {
+            let state = state.clone();
+            Rc::new(move |id| {
+                let marked = state.prefs.data.borrow_mut().warmup.toggle(&id);
+                log::info!("playlist {id} warm-up on launch: {marked}");
+                state.prefs.mark_dirty(Instant::now());
+            })
+        };
         let on_menu_close: Rc<dyn Fn()> = {
             let state = state.clone();
             let rebuild = rebuild.clone();
@@ -553,12 +729,27 @@ impl HomeView {
                 rebuild.set(true);
             })
         };
+        let on_copy_link: Rc<dyn Fn(&mut EventCtx, String)> = {
+            let state = state.clone();
+            Rc::new(move |ctx, url| {
+                ctx.tree.request_clipboard(&url);
+                state.toasts.show(tr("toast.link_copied"), ToastKind::Success);
+            })
+        };
+        let on_toast_dismiss: Rc<dyn Fn(u64)> = {
+            let state = state.clone();
+            // No rebuild: the toast animates out, and the frame tick
+            // rebuilds once it's gone.
+            Rc::new(move |id| state.toasts.dismiss(id))
+        };
         let on_clear_cache: Rc<dyn Fn()> = {
             let state = state.clone();
             let rebuild = rebuild.clone();
             Rc::new(move || {
                 let freed = state.settings.clear_cache();
                 log::info!("cleared disk cache (freed {freed} bytes)");
+                let msg = tr_n("toast.cache_cleared", crate::format::bytes(freed));
+                state.toasts.show(msg, ToastKind::Success);
                 rebuild.set(true);
             })
         };
@@ -595,9 +786,52 @@ impl HomeView {
                 worker.playback(token, PlaybackCmd::PlayContext(target), false);
             })
         };
-        let mark_dirty: Rc<dyn Fn()> = {
+        let on_search: Rc<dyn Fn(&str)> = {
+            let state = state.clone();
+            // Just records the text; the frame tick sends it once the field
+            // goes quiet and opens the results page.
+            Rc::new(move |text| state.search.edit(text, Instant::now()))
+        };
+        let on_search_submit: Rc<dyn Fn()> = {
+            let state = state.clone();
+            let on_play = on_play.clone();
+            Rc::new(move || {
+                // Enter: send now, or play the highlighted (else the top)
+                // song if its results are already showing.
+                state.search.flush();
+                if state.search.query() != state.search.text().trim() {
+                    return;
+                }
+                let pick = state.search.selected().unwrap_or(0);
+                let target = state.search.results.borrow().as_ref().and_then(|r| {
+                    r.tracks.iter().filter(|t| t.playable).nth(pick).map(track_play_target)
+                });
+                if let Some(target) = target {
+                    on_play(target);
+                }
+            })
+        };
+        let on_search_key: Rc<dyn Fn(Key) -> bool> = {
             let state = state.clone();
-            Rc::new(move || state.prefs.mark_dirty(Instant::now()))
+            let rebuild = rebuild.clone();
+            Rc::new(move |key| {
+                // Up/Down walk the song results while the page shows them;
+                // otherwise (and at either end) the field keeps the key.
+                let down = match key {
+                    Key::ArrowDown => true,
+                    Key::ArrowUp => false,
+                    _ => return false,
+                };
+                if *state.router.nav.borrow() != MainNav::Search || !state.search.step(down) {
+                    return false;
+                }
+                rebuild.set(true);
+                true
+            })
+        };
+        let on_resize: Rc<dyn Fn(Panel)> = {
+            let state = state.clone();
+            Rc::new(move |panel| state.prefs.resized(panel, Instant::now()))
         };
         Self {
             state,
@@ -606,23 +840,38 @@ impl HomeView {
             on_canvas_change,
             sign_out,
             on_settings_open,
+            on_search,
+            on_search_submit,
+            on_search_key,
             on_clear_cache,
             on_change_cache_dir,
             on_navigate,
             on_play,
             request_cover,
-            mark_dirty,
+            on_resize,
             on_devices_open,
             on_like_open,
             on_like_toggle_playlist,
             on_like_toggle_liked,
             on_transfer,
             on_quality,
+            on_quality_toggle,
+            on_output_device,
+            on_output_device_toggle,
+            on_locale,
+            on_locale_toggle,
             on_normalize,
+            on_warmup,
+            on_lyrics_lookup,
+            on_effects,
             on_skip,
+            on_seek,
             on_context_menu,
             on_add_queue,
+            on_toggle_warm,
             on_menu_close,
+            on_copy_link,
+            on_toast_dismiss,
         }
     }
 
@@ -631,6 +880,7 @@ impl HomeView {
     pub fn build(&self, s: &mut Scene) {
         let state = &self.state;
         let icons = &self.icons;
+        surface::set_reduced(state.reduced_effects());
         let nav = state.router.nav.borrow();
         // Hold the home borrow for the whole build (read-only feed data).
         // The art model is passed by reference and looked up narrowly per
@@ -639,9 +889,9 @@ impl HomeView {
         let home_ref = state.library.home.borrow();
         // Both playlist + album pages render through the playlist view (an
         // album is a track list with a context_uri); the hero label differs.
-        let kind_label = match &*nav {
-            MainNav::Album { .. } => "Album",
-            _ => "Playlist",
+        let kind_key = match &*nav {
+            MainNav::Album { .. } => "kind.album",
+            _ => "kind.playlist",
         };
         let playlist: Option<playlist::PlaylistViewData> = match &*nav {
             MainNav::Playlist { .. } | MainNav::Album { .. } => {
@@ -655,7 +905,7 @@ impl HomeView {
                         owner: o.owner.clone(),
                         total: o.total,
                         liked: o.liked,
-                        kind_label,
+                        kind_key,
                         loading: o.loading,
                         cover,
                         context_uri: o.context_uri.clone(),
@@ -666,9 +916,12 @@ impl HomeView {
                     }
                 })
             }
-            MainNav::Home | MainNav::Artist { .. } | MainNav::ShowAll { .. } | MainNav::Queue => {
-                None
-            }
+            MainNav::Home
+            | MainNav::Artist { .. }
+            | MainNav::ShowAll { .. }
+            | MainNav::Queue
+            | MainNav::Lyrics
+            | MainNav::Search => None,
         };
         // Artist page view data: bake album cover signals + lazily dispatch
         // their fetches (idempotent), mirroring how playlist rows resolve.
@@ -708,7 +961,7 @@ impl HomeView {
                         artist::ArtistTrack {
                             title: tk.name.clone(),
                             cover,
-                            duration: playlist::fmt_duration(tk.duration_ms),
+                            duration: crate::format::clock(tk.duration_ms),
                             uri: tk.uri.clone(),
                         }
                     })
@@ -726,6 +979,7 @@ impl HomeView {
         };
         let show_all_data: Option<show_all::ShowAllViewData> = match &*nav {
             MainNav::ShowAll { section } => Some(build_show_all(&state.art, &home_ref, *section)),
+            MainNav::Search => Some(build_search(&state.art, &state.search)),
             _ => None,
         };
         let now_playing = now_playing::NowPlaying {
@@ -735,6 +989,13 @@ impl HomeView {
             width: &state.prefs.now_playing_w,
         };
         let queue_ref = state.library.queue.borrow();
+        let lyrics_title = state
+            .player_ui
+            .snapshot
+            .borrow()
+            .as_ref()
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
         let player_bar = player_bar::PlayerBar {
             backdrop: &state.backdrop,
             player: &state.player_ui,
@@ -746,18 +1007,25 @@ impl HomeView {
             on_like_open: self.on_like_open.clone(),
             icons,
         };
+        let warm = state.prefs.data.borrow().warmup.playlists.clone();
         let sidebar = sidebar::Sidebar {
             width: &state.prefs.sidebar_w,
             accent: &state.backdrop.accent,
             nav: &nav,
             on_navigate: self.on_navigate.clone(),
+            on_context_menu: self.on_context_menu.clone(),
             home: &home_ref,
+            warm: &warm,
             art: &state.art,
             icons,
         };
         let top_bar = top_bar::TopBar {
             settings: &state.settings.overlay,
             on_settings_open: self.on_settings_open.clone(),
+            search_text: state.search.text(),
+            on_search: self.on_search.clone(),
+            on_search_submit: self.on_search_submit.clone(),
+            on_search_key: self.on_search_key.clone(),
             icons,
         };
         let main_pane = main_pane::MainPane {
@@ -772,6 +1040,10 @@ impl HomeView {
             queue: queue_ref.as_deref(),
             pulse: &state.library.skeleton_pulse,
             on_skip: self.on_skip.clone(),
+            lyrics: &state.lyrics,
+            lyrics_title: &lyrics_title,
+            on_lyrics_lookup: self.on_lyrics_lookup.clone(),
+            on_seek: self.on_seek.clone(),
             on_context_menu: self.on_context_menu.clone(),
             main_t: &state.router.main_t,
             detail_collapse: &state.router.detail_collapse,
@@ -782,6 +1054,8 @@ impl HomeView {
             settings: &state.settings,
             canvas: &state.canvas,
             backdrop: &state.backdrop,
+            power: &state.power,
+            lyrics: &state.lyrics,
             profile: home_ref.profile.as_ref(),
             icons,
             sign_out: self.sign_out.clone(),
@@ -790,7 +1064,17 @@ impl HomeView {
             on_change_cache_dir: self.on_change_cache_dir.clone(),
             quality: state.prefs.data.borrow().audio.quality,
             on_quality: self.on_quality.clone(),
+            on_quality_toggle: self.on_quality_toggle.clone(),
+            output_device: state.prefs.data.borrow().audio.output_device.clone(),
+            on_output_device: self.on_output_device.clone(),
+            on_output_device_toggle: self.on_output_device_toggle.clone(),
+            locale: state.prefs.data.borrow().locale.clone(),
+            on_locale: self.on_locale.clone(),
+            on_locale_toggle: self.on_locale_toggle.clone(),
             on_normalize: self.on_normalize.clone(),
+            on_warmup: self.on_warmup.clone(),
+            on_effects: self.on_effects.clone(),
+            on_lyrics_lookup: self.on_lyrics_lookup.clone(),
         };
         let devices_panel = devices::DevicesPanel {
             devices: &state.devices,
@@ -803,6 +1087,7 @@ impl HomeView {
             liked: &state.player_ui.liked,
             accent: &state.backdrop.accent,
             icons,
+            spin: &state.spin,
             on_toggle_playlist: self.on_like_toggle_playlist.clone(),
             on_toggle_liked: self.on_like_toggle_liked.clone(),
         };
@@ -813,7 +1098,7 @@ impl HomeView {
             art_luma: &state.backdrop.art_luma,
             sidebar_w: &state.prefs.sidebar_w,
             now_playing_w: &state.prefs.now_playing_w,
-            mark_dirty: self.mark_dirty.clone(),
+            on_resize: self.on_resize.clone(),
             now_playing: &now_playing,
             player_bar: &player_bar,
             sidebar: &sidebar,
@@ -825,7 +1110,13 @@ impl HomeView {
             menu: &state.menu,
             on_menu_add_queue: self.on_add_queue.clone(),
             on_menu_navigate: self.on_navigate.clone(),
+            on_menu_copy_link: self.on_copy_link.clone(),
+            on_menu_toggle_warm: self.on_toggle_warm.clone(),
             on_menu_close: self.on_menu_close.clone(),
+            toasts: &state.toasts,
+            on_toast_dismiss: self.on_toast_dismiss.clone(),
+            icons,
+            accent: &state.backdrop.accent,
         };
         render(s, &layout);
     }
@@ -887,9 +1178,13 @@ fn build_show_all(
                 // A song row plays the song (in its album context, so the
                 // queue continues) — it doesn't navigate; opening the album
                 // from a play-history list surprised more than it helped.
+                let subtitle = match show_all::played_ago(&t.played_at) {
+                    Some(ago) => format!("{} \u{2022} {}", t.artist, crate::format::relative(ago)),
+                    None => t.artist.clone(),
+                };
                 let row = ShowAllRow {
                     title: t.name.clone(),
-                    subtitle: t.artist.clone(),
+                    subtitle,
                     thumb: sig(&t.album_image_url),
                     round: false,
                     action: show_all::RowAction::Play(crate::api::PlayTarget::ContextAt {
@@ -900,6 +1195,7 @@ fn build_show_all(
                         uri: format!("spotify:track:{}", t.id),
                         album_id: t.album_id.clone(),
                         artist_id: String::new(),
+                        ..Default::default()
                     }),
                 };
                 if groups.last().map(|g| g.header.as_deref()) == Some(Some(label.as_str())) {
@@ -912,8 +1208,9 @@ fn build_show_all(
                 }
             }
             ShowAllViewData {
-                title: "Recently played".to_string(),
+                title: tr("home.recent"),
                 groups,
+                selected: None,
             }
         }
         HomeSection::TopArtists => {
@@ -922,7 +1219,7 @@ fn build_show_all(
                 .iter()
                 .map(|a| ShowAllRow {
                     title: a.name.clone(),
-                    subtitle: "Artist".to_string(),
+                    subtitle: tr("kind.artist"),
                     thumb: sig(&a.image_url),
                     round: true,
                     action: show_all::RowAction::Open(MainNav::Artist { id: a.id.clone() }),
@@ -930,8 +1227,9 @@ fn build_show_all(
                 })
                 .collect();
             ShowAllViewData {
-                title: "Your top artists".to_string(),
+                title: tr("home.top_artists"),
                 groups: vec![ShowAllGroup { header: None, rows }],
+                selected: None,
             }
         }
         HomeSection::TopTracks => {
@@ -953,12 +1251,14 @@ fn build_show_all(
                         uri: format!("spotify:track:{}", t.id),
                         album_id: t.album_id.clone(),
                         artist_id: String::new(),
+                        ..Default::default()
                     }),
                 })
                 .collect();
             ShowAllViewData {
-                title: "Your top tracks".to_string(),
+                title: tr("home.top_tracks"),
                 groups: vec![ShowAllGroup { header: None, rows }],
+                selected: None,
             }
         }
         HomeSection::Playlists => {
@@ -967,7 +1267,7 @@ fn build_show_all(
                 .iter()
                 .map(|p| ShowAllRow {
                     title: p.name.clone(),
-                    subtitle: "Playlist".to_string(),
+                    subtitle: tr("kind.playlist"),
                     thumb: sig(&p.image_url),
                     round: false,
                     action: show_all::RowAction::Open(MainNav::Playlist {
@@ -978,18 +1278,126 @@ fn build_show_all(
                 })
                 .collect();
             ShowAllViewData {
-                title: "Made For You".to_string(),
+                title: tr("home.made_for_you"),
                 groups: vec![ShowAllGroup { header: None, rows }],
+                selected: None,
             }
         }
     }
 }
 
+/// What playing a song row does: play it in its album context (so the
+/// queue continues), or alone when the album is unknown.
+fn track_play_target(t: &crate::api::PlaylistTrack) -> PlayTarget {
+    if t.album_id.is_empty() {
+        PlayTarget::Uris {
+            uris: vec![t.uri.clone()],
+            offset: 0,
+        }
+    } else {
+        PlayTarget::ContextAt {
+            context_uri: format!("spotify:album:{}", t.album_id),
+            track_uri: t.uri.clone(),
+        }
+    }
+}
+
+/// Assemble the search page as a [`show_all::ShowAllViewData`]: one headed
+/// group per non-empty section. Cover signals were created + dispatched in
+/// the reducer's `SearchLoaded` arm; this only reads them.
+fn build_search(
+    art: &crate::model::ArtModel,
+    search: &crate::model::SearchModel,
+) -> show_all::ShowAllViewData {
+    use show_all::{RowAction, ShowAllGroup, ShowAllRow, ShowAllViewData};
+    let sig = |url: &Option<String>| {
+        url.as_ref()
+            .and_then(|u| art.signal(&album_art::cache_key(u)))
+    };
+    let query = search.query();
+    let results = search.results.borrow();
+    let key = match results.as_ref() {
+        _ if query.is_empty() => "search.title",
+        None if search.failed() => "search.failed",
+        None => "search.pending",
+        Some(r) if r.is_empty() => "search.empty",
+        Some(_) => "search.results",
+    };
+    let title = tr(key).replace("{query}", &query);
+    let Some(r) = results.as_ref().filter(|_| !query.is_empty()) else {
+        return ShowAllViewData {
+            title,
+            groups: Vec::new(),
+            selected: None,
+        };
+    };
+    let songs: Vec<ShowAllRow> = r
+        .tracks
+        .iter()
+        .filter(|t| t.playable)
+        .map(|t| ShowAllRow {
+            title: t.name.clone(),
+            subtitle: t.artist.clone(),
+            thumb: sig(&t.album_image_url),
+            round: false,
+            action: RowAction::Play(track_play_target(t)),
+            menu: Some(crate::model::MenuTarget {
+                uri: t.uri.clone(),
+                album_id: t.album_id.clone(),
+                artist_id: t.artist_id.clone(),
+                ..Default::default()
+            }),
+        })
+        .collect();
+    let artists: Vec<ShowAllRow> = r
+        .artists
+        .iter()
+        .map(|a| ShowAllRow {
+            title: a.name.clone(),
+            subtitle: tr("kind.artist"),
+            thumb: sig(&a.image_url),
+            round: true,
+            action: RowAction::Open(MainNav::Artist { id: a.id.clone() }),
+            menu: None,
+        })
+        .collect();
+    let albums: Vec<ShowAllRow> = r
+        .albums
+        .iter()
+        .map(|a| ShowAllRow {
+            title: a.name.clone(),
+            subtitle: a.artist.clone(),
+            thumb: sig(&a.image_url),
+            round: false,
+            action: RowAction::Open(MainNav::Album { id: a.id.clone() }),
+            menu: None,
+        })
+        .collect();
+    let groups = [
+        ("search.songs", songs),
+        ("search.artists", artists),
+        ("search.albums", albums),
+    ]
+    .into_iter()
+    .filter(|(_, rows)| !rows.is_empty())
+    .map(|(header, rows)| ShowAllGroup {
+        header: Some(tr(header)),
+        rows,
+    })
+    .collect();
+    // Songs lead the page, so the song index is the row index.
+    ShowAllViewData {
+        title,
+        groups,
+        selected: search.selected(),
+    }
+}
+
 /// Switch the centre pane to `nav`. Ensures the target playlist is loaded
 /// (TTL cache → fetch on miss/stale) via the library slice, flips the nav
 /// state + entrance transition via the router, and requests the one scene
 /// rebuild that swaps the pane content.
-fn navigate(state: &Rc<AppState>, cx: &mut Cx, worker: &Worker, nav: MainNav) {
+pub(crate) fn navigate(state: &Rc<AppState>, cx: &mut Cx, worker: &Worker, nav: MainNav) {
     match &nav {
         MainNav::Playlist { id, liked } => {
             *state.library.open_artist.borrow_mut() = None;
@@ -1007,11 +1415,19 @@ fn navigate(state: &Rc<AppState>, cx: &mut Cx, worker: &Worker, nav: MainNav) {
             *state.library.open_playlist.borrow_mut() = None;
             state.library.open_artist(worker, state.auth.token(), id)
         }
-        MainNav::ShowAll { .. } | MainNav::Home => {
-            // Show-all renders from the already-loaded HomeData — no fetch.
+        MainNav::ShowAll { .. } | MainNav::Home | MainNav::Search => {
+            // Show-all renders from the already-loaded HomeData, search from
+            // the search model (fetched by the frame tick) — no fetch here.
             *state.library.open_playlist.borrow_mut() = None;
             *state.library.open_artist.borrow_mut() = None;
         }
+        MainNav::Lyrics => {
+            *state.library.open_playlist.borrow_mut() = None;
+            *state.library.open_artist.borrow_mut() = None;
+            if let Some(p) = state.player_ui.snapshot.borrow().as_ref() {
+                state.lyrics.ensure(worker, p);
+            }
+        }
         MainNav::Queue => {
             *state.library.open_playlist.borrow_mut() = None;
             *state.library.open_artist.borrow_mut() = None;
@@ -7,6 +7,7 @@
 
 use opal_gfx::{Computed, Justify, Len, Overflow, Scene, Signal};
 
+use crate::i18n::tr;
 use crate::model::{BackdropModel, CanvasModel, PlayerModel};
 use crate::widgets::component::Component;
 use crate::widgets::crossfade::crossfaded_art;
@@ -48,7 +49,7 @@ impl NowPlaying<'_> {
             .radius(t::R_LG)
             .overflow_x(Overflow::Hidden)
             .child(|c| {
-                c.text((), "Now playing", 16.0).color(t::TEXT);
+                c.text((), tr("now_playing.title"), 16.0).color(t::TEXT);
                 c.col(()).w(Len::Fill).square().child(|b| {
                     crossfaded_art(
                         b,
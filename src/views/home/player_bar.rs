@@ -12,13 +12,16 @@ use std::time::Duration;
 
 use opal_gfx::{Align, Computed, Curve, CursorIcon, Justify, Len, Scene};
 
+use crate::i18n;
+use crate::model::player::PlayerAction;
 use crate::model::{BackdropModel, DevicesModel, MembershipModel, PlayerModel};
 use crate::views::MainNav;
-use crate::views::home::{NavFn, PlayerAction};
+use crate::views::home::NavFn;
 use crate::widgets::color::accent_fg;
 use crate::widgets::component::Component;
 use crate::widgets::crossfade::crossfaded_art;
 use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::motion;
 use crate::widgets::tokens as t;
 
 /// Progress-bar quantization steps across its full width. The fill only
@@ -152,14 +155,26 @@ impl Component for PlayerBar<'_> {
                                     (self.player.shuffle.clone(), self.backdrop.accent.clone()),
                                     |(on, acc)| if on { acc } else { t::TEXT_DIM },
                                 );
-                                transport_btn(tr, icons, Icon::Shuffle, t::ICON_MD, shuffle_tint, {
-                                    let act = self.on_action.clone();
-                                    move || act(PlayerAction::ToggleShuffle)
-                                });
-                                transport_btn(tr, icons, Icon::SkipBack, t::ICON_LG, t::TEXT, {
-                                    let act = self.on_action.clone();
-                                    move || act(PlayerAction::Prev)
-                                });
+                                let shuffle_act = self.on_action.clone();
+                                transport_btn(
+                                    tr,
+                                    icons,
+                                    Icon::Shuffle,
+                                    &i18n::tr("player.shuffle"),
+                                    t::ICON_MD,
+                                    shuffle_tint,
+                                    move || shuffle_act(PlayerAction::ToggleShuffle),
+                                );
+                                let prev_act = self.on_action.clone();
+                                transport_btn(
+                                    tr,
+                                    icons,
+                                    Icon::SkipBack,
+                                    &i18n::tr("player.previous"),
+                                    t::ICON_LG,
+                                    t::TEXT,
+                                    move || prev_act(PlayerAction::Prev),
+                                );
                                 let play_h = icons.get(Icon::Play);
                                 let pause_h = icons.get(Icon::Pause);
                                 let play_glyph =
@@ -174,6 +189,7 @@ impl Component for PlayerBar<'_> {
                                     .hover_opacity(0.85)
                                     .radius(t::R_FULL)
                                     .center()
+                                    .hover_hint(&i18n::tr("player.play_pause"))
                                     .on_click(move |_| play_act(PlayerAction::PlayPause))
                                     .child(|p| {
                                         p.image_bound((), play_glyph)
@@ -181,18 +197,30 @@ impl Component for PlayerBar<'_> {
                                             .h_px(t::SP_4)
                                             .color(accent_fg(&self.backdrop.accent));
                                     });
-                                transport_btn(tr, icons, Icon::SkipForward, t::ICON_LG, t::TEXT, {
-                                    let act = self.on_action.clone();
-                                    move || act(PlayerAction::Next)
-                                });
+                                let next_act = self.on_action.clone();
+                                transport_btn(
+                                    tr,
+                                    icons,
+                                    Icon::SkipForward,
+                                    &i18n::tr("player.next"),
+                                    t::ICON_LG,
+                                    t::TEXT,
+                                    move || next_act(PlayerAction::Next),
+                                );
                                 let repeat_tint = Computed::new(
                                     (self.player.repeat_on.clone(), self.backdrop.accent.clone()),
                                     |(on, acc)| if on { acc } else { t::TEXT_DIM },
                                 );
-                                transport_btn(tr, icons, Icon::Repeat, t::ICON_MD, repeat_tint, {
-                                    let act = self.on_action.clone();
-                                    move || act(PlayerAction::CycleRepeat)
-                                });
+                                let repeat_act = self.on_action.clone();
+                                transport_btn(
+                                    tr,
+                                    icons,
+                                    Icon::Repeat,
+                                    &i18n::tr("player.repeat"),
+                                    t::ICON_MD,
+                                    repeat_tint,
+                                    move || repeat_act(PlayerAction::CycleRepeat),
+                                );
                             });
                         // Scrubbable progress bar. The lane is taller than
                         // the visible track so it's easy to grab; the
@@ -209,9 +237,16 @@ impl Component for PlayerBar<'_> {
                     .align(Align::Center)
                     .justify(Justify::End)
                     .child(|r| {
+                        // Lyrics page.
+                        let nav = self.on_navigate.clone();
+                        let hint = i18n::tr("player.lyrics");
+                        icon_btn(r, icons, Icon::Lyrics, &hint, t::TEXT_DIM.into(), move |ctx| {
+                            nav(ctx, MainNav::Lyrics)
+                        });
                         // Queue page.
                         let nav = self.on_navigate.clone();
-                        icon_btn(r, icons, Icon::Queue, t::TEXT_DIM.into(), move |ctx| {
+                        let hint = i18n::tr("player.queue");
+                        icon_btn(r, icons, Icon::Queue, &hint, t::TEXT_DIM.into(), move |ctx| {
                             nav(ctx, MainNav::Queue)
                         });
                         // Devices popup — accent-lit only when another device
@@ -227,7 +262,8 @@ impl Component for PlayerBar<'_> {
                         );
                         let dev_overlay = self.devices.overlay.clone();
                         let on_devices_open = self.on_devices_open.clone();
-                        icon_btn(r, icons, Icon::Devices, dev_tint.into(), move |ctx| {
+                        let hint = i18n::tr("player.devices");
+                        icon_btn(r, icons, Icon::Devices, &hint, dev_tint.into(), move |ctx| {
                             dev_overlay.open(ctx.timeline, ctx.now);
                             on_devices_open();
                         });
@@ -423,7 +459,7 @@ impl PlayerBar<'_> {
                     &wheel_vol,
                     target,
                     Curve::EaseInOut,
-                    Duration::from_millis(140),
+                    motion::dur(Duration::from_millis(140)),
                     ctx.now,
                 );
                 wheel_h.label_at_frac(target, ctx.rect[2] / ctx.scale.max(1.0));
@@ -480,11 +516,13 @@ const TIP_W: f32 = 96.0;
 
 
 /// Clickable bare icon (no background pill) for the player-bar utilities.
-/// `tint` takes a static colour or a reactive bind (active-state tints).
+/// `tint` takes a static colour or a reactive bind (active-state tints);
+/// `hint` is the hover tooltip (the glyph alone doesn't name the action).
 fn icon_btn(
     s: &mut Scene,
     icons: &IconSet,
     icon: Icon,
+    hint: &str,
     tint: opal_gfx::Bind<[f32; 4]>,
     on_click: impl for<'h> Fn(&mut opal_gfx::EventCtx<'h>) + 'static,
 ) {
@@ -493,6 +531,7 @@ fn icon_btn(
         .h_px(t::SP_7)
         .center()
         .hover_opacity(0.8)
+        .hover_hint(hint)
         .on_click(on_click)
         .child(|c| {
             icons.render(c, icon, t::ICON_MD, tint);
@@ -501,11 +540,12 @@ fn icon_btn(
 
 /// Transport icon button. `tint` accepts a static colour, a `Signal`, or a
 /// `Computed` (via `Into<Bind>`), so active-toggle states route a reactive
-/// tint and update without a rebuild.
+/// tint and update without a rebuild. `hint` is the hover tooltip.
 fn transport_btn(
     s: &mut Scene,
     icons: &IconSet,
     icon: Icon,
+    hint: &str,
     size: f32,
     tint: impl Into<opal_gfx::Bind<[f32; 4]>>,
     on_click: impl Fn() + 'static,
@@ -515,6 +555,7 @@ fn transport_btn(
         .h_px(t::SP_8)
         .center()
         .hover_opacity(0.7)
+        .hover_hint(hint)
         .on_click(move |_| on_click())
         .child(|c| {
             icons.render(c, icon, size, tint);
@@ -19,10 +19,13 @@ use std::rc::Rc;
 use opal_gfx::{Align, Computed, ImageHandle, Justify, Len, Overflow, Scene, Signal};
 
 use crate::api::PlayTarget;
+use crate::format;
+use crate::i18n::tr;
 use crate::views::MainNav;
 use crate::views::home::{NavFn, PlayFn};
 use crate::widgets::color::accent_fg;
 use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::surface;
 use crate::widgets::tokens as t;
 
 /// Track-row height. Thumb (40) + breathing room.
@@ -59,6 +62,8 @@ pub struct PlaylistRow {
     pub artist: String,
     pub album: String,
     pub duration: String,
+    /// Run time behind `duration`, summed for the header's total length.
+    pub duration_ms: u64,
     pub uri: String,
     /// Reactive cover handle (bound via `image_bound`). `None` if the
     /// track has no cover URL; the inner `Signal` stays `None` until the
@@ -96,8 +101,8 @@ pub struct PlaylistViewData {
     /// before every page has streamed in.
     pub total: u32,
     pub liked: bool,
-    /// Hero eyebrow label — "Playlist" or "Album".
-    pub kind_label: &'static str,
+    /// Hero eyebrow label key — `kind.playlist` or `kind.album`.
+    pub kind_key: &'static str,
     /// Metadata not yet arrived (header shows the sidebar-known name, the
     /// list shows skeletons).
     pub loading: bool,
@@ -246,7 +251,7 @@ struct HeroData {
     cover: Option<Signal<Option<ImageHandle>>>,
     liked: bool,
     total: u32,
-    kind_label: &'static str,
+    kind_key: &'static str,
     loading: bool,
     context_uri: Option<String>,
     rows: RowBuf,
@@ -261,7 +266,7 @@ impl HeroData {
             cover: d.cover.clone(),
             liked: d.liked,
             total: d.total,
-            kind_label: d.kind_label,
+            kind_key: d.kind_key,
             loading: d.loading,
             context_uri: d.context_uri.clone(),
             rows: d.rows.clone(),
@@ -308,14 +313,15 @@ fn hero_block(
                 .child(|h| {
                     cover_art(h, icons, d.cover.clone(), d.liked);
                     h.col(()).gap(t::SP_2).justify(Justify::End).child(|m| {
-                        m.text((), d.kind_label, 12.0).color(t::TEXT_DIM);
+                        m.text((), tr(d.kind_key), 12.0).color(t::TEXT_DIM);
                         m.text((), &d.name, 32.0).color(t::TEXT).max_width_px(520.0);
                         m.row(()).gap(t::SP_1_5).align(Align::Center).child(|sub| {
                             if !d.owner.is_empty() {
                                 sub.text((), &d.owner, 12.0).color(t::TEXT);
                                 sub.text((), "•", 12.0).color(t::TEXT_DIM);
                             }
-                            sub.text((), count_label(d.total, d.loading), 12.0)
+                            let label = count_label(d.total, d.loading, &d.rows.borrow());
+                            sub.text((), label, 12.0)
                                 .color(t::TEXT_DIM);
                         });
                     });
@@ -363,13 +369,13 @@ fn strip_items(h: &mut Scene) {
         .h_px(t::SP_PX)
         .rgba(0.0, 0.0, 0.0, 0.0);
     h.col(()).w(Len::Fill).child(|x| {
-        x.text((), "Title", 12.0).color(t::TEXT_DIM);
+        x.text((), tr("playlist.col.title"), 12.0).color(t::TEXT_DIM);
     });
     h.col(()).w_px(t::SP_48).child(|x| {
-        x.text((), "Album", 12.0).color(t::TEXT_DIM);
+        x.text((), tr("playlist.col.album"), 12.0).color(t::TEXT_DIM);
     });
     h.row(()).w_px(t::SP_12).justify(Justify::End).child(|x| {
-        x.text((), "Time", 12.0).color(t::TEXT_DIM);
+        x.text((), tr("playlist.col.time"), 12.0).color(t::TEXT_DIM);
     });
 }
 
@@ -403,7 +409,7 @@ fn column_header(s: &mut Scene, collapse: &Signal<f32>) {
 /// Single centred row used when a playlist is genuinely empty.
 fn empty_row(s: &mut Scene) {
     s.row(()).w(Len::Fill).h_px(ROW_H).center().child(|c| {
-        c.text((), "No songs here yet", 14.0).color(t::TEXT_DIM);
+        c.text((), tr("playlist.empty"), 14.0).color(t::TEXT_DIM);
     });
 }
 
@@ -439,12 +445,10 @@ fn sticky_bar(
     // backdrop pass composites every layer below this one (ambient art +
     // root + the track-list scroll layer), so the header genuinely frosts
     // the rows sliding under it.
-    s.glass(())
-        .pos(slide)
+    let mut bar = surface::frosted(s, 10.0, [t::PANEL[0], t::PANEL[1], t::PANEL[2]], 0.72);
+    bar.pos(slide)
         .w(Len::Fill)
         .h_px(total_h)
-        .blur(10.0)
-        .rgba(t::PANEL[0], t::PANEL[1], t::PANEL[2], 0.72)
         // Round only the TOP corners to match the centre pane (`main_area`,
         // R_LG); bottom stays square (it meets the list + hairline). Needed
         // because this is its own `.layer()` — composited separately, so the
@@ -519,19 +523,19 @@ fn sticky_bar(
         });
 }
 
-/// Header count label — "Loading…" until tracks land, then "N songs".
-fn count_label(total: u32, loading: bool) -> String {
-    if total == 0 {
-        if loading {
-            "Loading…".to_string()
-        } else {
-            "0 songs".to_string()
-        }
-    } else if total == 1 {
-        "1 song".to_string()
-    } else {
-        format!("{total} songs")
+/// Header count label — "Loading…" until tracks land, then "N songs",
+/// and "N songs • 3 hr 24 min" once every row has streamed in (the hero is
+/// re-materialized with the list, so the length appears with the last page).
+fn count_label(total: u32, loading: bool, rows: &[PlaylistRow]) -> String {
+    if total == 0 && loading {
+        return tr("page.loading");
     }
+    let songs = format::count(u64::from(total), "song");
+    if total == 0 || rows.len() < total as usize {
+        return songs;
+    }
+    let length: u64 = rows.iter().map(|r| r.duration_ms).sum();
+    format!("{songs} \u{2022} {}", format::total_length(length))
 }
 
 /// Square cover. Liked Songs has no image — render the signature
@@ -626,6 +630,7 @@ fn track_row(
             uri: r.uri.clone(),
             album_id: r.album_id.clone(),
             artist_id: r.artist_id.clone(),
+            ..Default::default()
         },
     );
     row.child(|row| {
@@ -762,9 +767,3 @@ fn skeleton_row(s: &mut Scene, index: u32, pulse: &Signal<f32>) {
                 });
         });
 }
-
-/// `ms` → `m:ss`.
-pub fn fmt_duration(ms: u64) -> String {
-    let secs = ms / 1000;
-    format!("{}:{:02}", secs / 60, secs % 60)
-}
@@ -12,6 +12,7 @@ use std::rc::Rc;
 use opal_gfx::{Align, CursorIcon, Justify, Len, Overflow, Scene, Signal};
 
 use crate::api::PlaylistTrack;
+use crate::i18n::tr;
 use crate::model::ArtModel;
 use crate::views::MainNav;
 use crate::views::home::{CtxMenuFn, NavFn};
@@ -57,7 +58,7 @@ pub fn view(
                 .center()
                 .on_click(move |ctx| nav_back(ctx, MainNav::Home))
                 .child(|b| icons.render(b, Icon::ChevronLeft, t::ICON_MD, t::TEXT));
-            c.text((), "Queue", 28.0).color(t::TEXT).max_width_px(520.0);
+            c.text((), tr("queue.title"), 28.0).color(t::TEXT).max_width_px(520.0);
 
             match queue {
                 None => {
@@ -68,24 +69,24 @@ pub fn view(
                 }
                 Some([]) => {
                     c.row(()).w(Len::Fill).h_px(ROW_H).center().child(|e| {
-                        e.text((), "Nothing queued", 14.0).color(t::TEXT_DIM);
+                        e.text((), tr("queue.empty"), 14.0).color(t::TEXT_DIM);
                     });
                 }
                 Some(tracks) => {
                     let mut it = tracks.iter().enumerate();
                     if let Some((_, now)) = it.next() {
-                        section_label(c, "Now playing");
+                        section_label(c, &tr("queue.now"));
                         queue_row(c, now, art, None, &on_context_menu, &nav_rows);
                     }
-                    section_label(c, "Next up");
-                    for (i, tr) in it {
+                    section_label(c, &tr("queue.next"));
+                    for (i, track) in it {
                         // Clicking the i-th item (1-based from the playing
                         // track) skips forward `i` tracks to reach it.
                         let on_skip = on_skip.clone();
                         let n = i as u32;
                         queue_row(
                             c,
-                            tr,
+                            track,
                             art,
                             Some(Rc::new(move || on_skip(n))),
                             &on_context_menu,
@@ -111,7 +112,7 @@ fn section_label(s: &mut Scene, label: &str) {
 /// clickable (`on_click` skips to them); all rows render at full opacity.
 fn queue_row(
     s: &mut Scene,
-    tr: &PlaylistTrack,
+    track: &PlaylistTrack,
     art: &ArtModel,
     on_click: Option<Rc<dyn Fn()>>,
     on_context_menu: &CtxMenuFn,
@@ -119,7 +120,7 @@ fn queue_row(
 ) {
     // Signals exist (created + dispatched in the reducer's `QueueLoaded`
     // arm — view builds stay pure reads); this just binds them.
-    let cover = tr
+    let cover = track
         .album_image_url
         .as_ref()
         .and_then(|u| art.signal(&crate::album_art::cache_key(u)));
@@ -140,9 +141,10 @@ fn queue_row(
         &mut row,
         on_context_menu,
         crate::model::MenuTarget {
-            uri: tr.uri.clone(),
-            album_id: tr.album_id.clone(),
-            artist_id: tr.artist_id.clone(),
+            uri: track.uri.clone(),
+            album_id: track.album_id.clone(),
+            artist_id: track.artist_id.clone(),
+            ..Default::default()
         },
     );
     row.child(|r| {
@@ -170,17 +172,17 @@ fn queue_row(
             .justify(Justify::Center)
             .overflow_x(Overflow::Hidden)
             .child(|m| {
-                m.text((), &tr.name, 14.0).color(t::TEXT).max_width_px(420.0);
+                m.text((), &track.name, 14.0).color(t::TEXT).max_width_px(420.0);
                 crate::views::home::playlist::artist_line(
                     m,
-                    &tr.artists,
-                    &tr.artist,
+                    &track.artists,
+                    &track.artist,
                     on_navigate,
                     420.0,
                 );
             });
         r.row(()).push_end().w_px(t::SP_12).justify(Justify::End).child(|d| {
-            d.text((), crate::views::home::playlist::fmt_duration(tr.duration_ms), 12.0)
+            d.text((), crate::format::clock(track.duration_ms), 12.0)
                 .color(t::TEXT_DIM);
         });
     });
@@ -5,19 +5,22 @@
 //! what goes inside it. `home::build` calls `overlay.render(.., |panel|
 //! settings::panel(panel, ..))`.
 //!
-//! The notable bit is [`toggle_switch`] — the animated on/off switch.
+//! Rows pair a label with the shared [`toggle_switch`] widget.
 
 use std::rc::Rc;
-use std::time::Duration;
 
-use opal_gfx::{Align, Computed, Curve, Len, Overlay, Scene, Signal};
+use opal_gfx::{Align, Len, Overlay, Scene, Signal};
 
 use crate::api::Profile;
 use crate::disk_cache::{self, CacheUsage};
-use crate::model::{BackdropModel, CanvasModel, SettingsModel};
+use crate::format;
+use crate::i18n::{tr, tr_n};
+use crate::model::{BackdropModel, CanvasModel, LyricsModel, PowerModel, SettingsModel};
 use crate::widgets::component::Component;
+use crate::widgets::dropdown::Dropdown;
 use crate::widgets::icon::{Icon, IconSet};
 use crate::widgets::tokens as t;
+use crate::widgets::toggle::toggle_switch;
 
 /// Colour of the album-art segment in the cache usage bar.
 const CACHE_ART_COL: [f32; 4] = [0.36, 0.7, 0.95, 1.0];
@@ -28,20 +31,6 @@ const CACHE_JSON_COL: [f32; 4] = [0.55, 0.82, 0.55, 1.0];
 /// Colour of the streamed-audio segment in the cache usage bar.
 const CACHE_AUDIO_COL: [f32; 4] = [0.95, 0.68, 0.38, 1.0];
 
-// Animated toggle dimensions (logical px). The knob slides `TRAVEL` px
-// between the two pad-inset ends of the track.
-const TOGGLE_W: f32 = 44.0;
-const TOGGLE_H: f32 = 24.0;
-const TOGGLE_KNOB: f32 = 18.0;
-const TOGGLE_PAD: f32 = 3.0;
-const TOGGLE_TRAVEL: f32 = TOGGLE_W - TOGGLE_KNOB - 2.0 * TOGGLE_PAD;
-/// Off-state track colour — a faint white so the switch reads as a
-/// recessed pill before it lights up to the accent.
-const TOGGLE_OFF: [f32; 4] = [1.0, 1.0, 1.0, 0.18];
-/// Track + knob tween — snappy enough to feel responsive, slow enough to
-/// read as motion.
-const TOGGLE_MS: u64 = 160;
-
 const PANEL_W: f32 = 420.0;
 /// Capped panel height (logical px). Current content fits within this, so
 /// the body doesn't scroll yet; it caps growth so the modal never exceeds
@@ -57,6 +46,8 @@ pub struct SettingsPanel<'a> {
     pub settings: &'a SettingsModel,
     pub canvas: &'a CanvasModel,
     pub backdrop: &'a BackdropModel,
+    pub power: &'a PowerModel,
+    pub lyrics: &'a LyricsModel,
     pub profile: Option<&'a Profile>,
     pub icons: &'a Rc<IconSet>,
     /// Clear the stored token + return to Login.
@@ -67,12 +58,32 @@ pub struct SettingsPanel<'a> {
     pub on_clear_cache: Rc<dyn Fn()>,
     /// Open a folder picker to relocate the cache.
     pub on_change_cache_dir: Rc<dyn Fn()>,
-    /// Current streaming-quality preference (selected chip).
+    /// Current streaming-quality preference (the dropdown's selection).
     pub quality: crate::prefs::AudioQuality,
     /// Persist a new streaming-quality choice.
     pub on_quality: Rc<dyn Fn(crate::prefs::AudioQuality)>,
+    /// Expand/collapse the quality dropdown.
+    pub on_quality_toggle: Rc<dyn Fn()>,
+    /// Saved output device (`None` = system default).
+    pub output_device: Option<String>,
+    /// Persist a new output-device choice.
+    pub on_output_device: Rc<dyn Fn(Option<String>)>,
+    /// Expand/collapse the output-device dropdown.
+    pub on_output_device_toggle: Rc<dyn Fn()>,
     /// Persist the "Normalize volume" toggle after it flips.
     pub on_normalize: Rc<dyn Fn()>,
+    /// Persist the "Warm up playlists" toggle after it flips.
+    pub on_warmup: Rc<dyn Fn()>,
+    /// Persist either "Reduce effects" toggle after it flips.
+    pub on_effects: Rc<dyn Fn()>,
+    /// Saved UI locale code (`None` = English).
+    pub locale: Option<String>,
+    /// Switch to a picked locale code, persist it and rebuild.
+    pub on_locale: Rc<dyn Fn(String)>,
+    /// Expand/collapse the language dropdown.
+    pub on_locale_toggle: Rc<dyn Fn()>,
+    /// Persist the "Look up lyrics" toggle after it flips.
+    pub on_lyrics_lookup: Rc<dyn Fn()>,
 }
 
 impl Component for SettingsPanel<'_> {
@@ -110,28 +121,63 @@ impl Component for SettingsPanel<'_> {
                         .scroll_y()
                         .scrollbar(|sb| sb.auto_hide(true).margin(t::SP_0_5).thickness(t::SP_1))
                         .child(|body| {
+                            // Built without Canvas playback: no toggle.
+                            if cfg!(feature = "canvas-video") {
+                                setting_row(
+                                    body,
+                                    &tr("settings.canvas"),
+                                    &tr("settings.canvas.caption"),
+                                    &self.canvas.show,
+                                    &self.backdrop.accent,
+                                    self.on_canvas_change.clone(),
+                                );
+                                divider(body);
+                            }
+                            language_row(body, icons, self);
+                            quality_row(body, icons, self);
+                            output_device_row(body, icons, self);
                             setting_row(
                                 body,
-                                "Show canvas video",
-                                "Looping artist visual in the now-playing pane",
-                                &self.canvas.show,
+                                &tr("settings.normalize"),
+                                &tr("settings.normalize.caption"),
+                                &self.settings.normalize,
                                 &self.backdrop.accent,
-                                self.on_canvas_change.clone(),
+                                self.on_normalize.clone(),
                             );
                             divider(body);
-                            quality_row(
+                            setting_row(
                                 body,
-                                self.quality,
+                                &tr("settings.warmup"),
+                                &tr("settings.warmup.caption"),
+                                &self.settings.warmup,
                                 &self.backdrop.accent,
-                                self.on_quality.clone(),
+                                self.on_warmup.clone(),
                             );
+                            divider(body);
                             setting_row(
                                 body,
-                                "Normalize volume",
-                                "Match loudness across tracks + prevent clipping (next launch)",
-                                &self.settings.normalize,
+                                &tr("settings.lyrics"),
+                                &tr("settings.lyrics.caption"),
+                                &self.lyrics.lookup,
                                 &self.backdrop.accent,
-                                self.on_normalize.clone(),
+                                self.on_lyrics_lookup.clone(),
+                            );
+                            divider(body);
+                            setting_row(
+                                body,
+                                &tr("settings.reduce"),
+                                &tr("settings.reduce.caption"),
+                                &self.power.reduce,
+                                &self.backdrop.accent,
+                                self.on_effects.clone(),
+                            );
+                            setting_row(
+                                body,
+                                &tr("settings.reduce_battery"),
+                                &tr("settings.reduce_battery.caption"),
+                                &self.power.reduce_on_battery,
+                                &self.backdrop.accent,
+                                self.on_effects.clone(),
                             );
                             divider(body);
                             cache_section(
@@ -154,63 +200,127 @@ fn divider(s: &mut Scene) {
     s.rect(()).w(Len::Fill).h_px(t::SP_PX).rgba(1.0, 1.0, 1.0, 0.06);
 }
 
-/// Streaming-quality picker: three chips (96 / 160 / 320 kbps), the
-/// active one accent-filled. Bitrate is baked into the librespot player
-/// at session start, so a change applies from the next launch — the
-/// caption says so rather than pretending it's instant.
-fn quality_row(
-    s: &mut Scene,
-    current: crate::prefs::AudioQuality,
-    accent: &Signal<[f32; 4]>,
-    on_quality: Rc<dyn Fn(crate::prefs::AudioQuality)>,
-) {
+/// UI language picker: English plus every bundle found in the locales
+/// folder (listed on settings open). Applied at once — the switch
+/// rebuilds the scene, so every keyed string re-resolves.
+fn language_row(s: &mut Scene, icons: &IconSet, panel: &SettingsPanel) {
+    let locales = panel.settings.locales.borrow();
+    let options: Vec<&str> = locales.iter().map(|(_, name)| name.as_str()).collect();
+    let current = panel.locale.as_deref().unwrap_or("en");
+    let codes: Vec<String> = locales.iter().map(|(code, _)| code.clone()).collect();
+    let on_locale = panel.on_locale.clone();
+    let dropdown = Dropdown {
+        options: &options,
+        selected: codes.iter().position(|c| c == current).unwrap_or(0),
+        open: panel.settings.locale_open.get(),
+        accent: &panel.backdrop.accent,
+        on_toggle: panel.on_locale_toggle.clone(),
+        on_select: Rc::new(move |i| on_locale(codes[i].clone())),
+    };
+    s.col(()).w(Len::Fill).gap(t::SP_2).child(|c| {
+        c.col(()).gap(t::SP_0_5).child(|m| {
+            m.text((), tr("settings.language"), 14.0).color(t::TEXT);
+            m.text((), tr("settings.language.caption"), t::TEXT_XS)
+                .color(t::TEXT_DIM);
+        });
+        dropdown.view(c, icons);
+    });
+}
+
+/// Streaming-quality picker: a dropdown of the three tiers (96 / 160 /
+/// 320 kbps). Bitrate is baked into the librespot player at session
+/// start, so a change applies from the next launch — the caption says so
+/// rather than pretending it's instant.
+fn quality_row(s: &mut Scene, icons: &IconSet, panel: &SettingsPanel) {
     use crate::prefs::AudioQuality as Q;
-    s.col(()).w(Len::Fill).gap(t::SP_2).child(move |c| {
+    const TIERS: [Q; 3] = [Q::Low, Q::Normal, Q::High];
+    let on_quality = panel.on_quality.clone();
+    let options = [
+        tr("settings.quality.low"),
+        tr("settings.quality.normal"),
+        tr("settings.quality.high"),
+    ];
+    let dropdown = Dropdown {
+        options: &options.each_ref().map(String::as_str),
+        selected: TIERS.iter().position(|q| *q == panel.quality).unwrap_or(2),
+        open: panel.settings.quality_open.get(),
+        accent: &panel.backdrop.accent,
+        on_toggle: panel.on_quality_toggle.clone(),
+        on_select: Rc::new(move |i| on_quality(TIERS[i])),
+    };
+    s.col(()).w(Len::Fill).gap(t::SP_2).child(|c| {
         c.col(()).gap(t::SP_0_5).child(|m| {
-            m.text((), "Streaming quality", 14.0).color(t::TEXT);
-            m.text((), "Applies on next launch", t::TEXT_XS).color(t::TEXT_DIM);
+            m.text((), tr("settings.quality"), 14.0).color(t::TEXT);
+            m.text((), tr("settings.next_launch"), t::TEXT_XS)
+                .color(t::TEXT_DIM);
         });
-        c.row(()).gap(t::SP_2).child(move |row| {
-            for (q, label) in [(Q::Low, "Low 96k"), (Q::Normal, "Normal 160k"), (Q::High, "High 320k")] {
-                let selected = q == current;
-                let mut chip = row.row(());
-                chip.h_px(t::CHIP_H)
-                    .pad_xy(t::SP_3_5, t::SP_0)
-                    .center()
-                    .radius(t::R_FULL);
-                if selected {
-                    chip.color(accent.clone()).child(|x| {
-                        x.text((), label, 13.0)
-                            .color(crate::widgets::color::accent_fg(accent));
-                    });
-                } else {
-                    let on_quality = on_quality.clone();
-                    chip.color(t::PANEL_HI)
-                        .hover_opacity(0.8)
-                        .on_click(move |_| on_quality(q))
-                        .child(|x| {
-                            x.text((), label, 13.0).color(t::TEXT);
-                        });
-                }
-            }
+        dropdown.view(c, icons);
+    });
+}
+
+/// Output-device picker: "System default" then every output the audio
+/// host lists. Like quality, the sink is opened once at session start, so
+/// the choice applies on next launch. A saved device that's currently
+/// unplugged still shows (selected) so the pick isn't silently lost.
+fn output_device_row(s: &mut Scene, icons: &IconSet, panel: &SettingsPanel) {
+    let choices = DeviceChoices::new(
+        &panel.settings.output_devices.borrow(),
+        panel.output_device.as_deref(),
+    );
+    let default = tr("settings.output_device.default");
+    let options: Vec<&str> = std::iter::once(default.as_str())
+        .chain(choices.names.iter().map(String::as_str))
+        .collect();
+    let on_output_device = panel.on_output_device.clone();
+    let picks = choices.names.clone();
+    let dropdown = Dropdown {
+        options: &options,
+        selected: choices.selected,
+        open: panel.settings.device_open.get(),
+        accent: &panel.backdrop.accent,
+        on_toggle: panel.on_output_device_toggle.clone(),
+        on_select: Rc::new(move |i| on_output_device(DeviceChoices::pick(&picks, i))),
+    };
+    s.col(()).w(Len::Fill).gap(t::SP_2).child(|c| {
+        c.col(()).gap(t::SP_0_5).child(|m| {
+            m.text((), tr("settings.output_device"), 14.0)
+                .color(t::TEXT);
+            m.text((), tr("settings.next_launch"), t::TEXT_XS)
+                .color(t::TEXT_DIM);
         });
+        dropdown.view(c, icons);
     });
 }
 
-/// Human-readable byte size (e.g. `1.2 GB`, `340 MB`, `12 KB`).
-fn fmt_bytes(b: u64) -> String {
-    const KB: f64 = 1024.0;
-    const MB: f64 = KB * 1024.0;
-    const GB: f64 = MB * 1024.0;
-    let f = b as f64;
-    if f >= GB {
-        format!("{:.2} GB", f / GB)
-    } else if f >= MB {
-        format!("{:.1} MB", f / MB)
-    } else if f >= KB {
-        format!("{:.0} KB", f / KB)
-    } else {
-        format!("{b} B")
+/// The device dropdown's model: option 0 is "System default", option
+/// `i + 1` is `names[i]`.
+struct DeviceChoices {
+    /// Listed outputs, plus the saved one appended if it isn't listed.
+    names: Rc<[String]>,
+    /// Dropdown index of the saved device (0 when none is saved).
+    selected: usize,
+}
+
+impl DeviceChoices {
+    fn new(listed: &[String], saved: Option<&str>) -> Self {
+        let mut names = listed.to_vec();
+        if let Some(saved) = saved
+            && !names.iter().any(|n| n == saved)
+        {
+            names.push(saved.to_owned());
+        }
+        let selected = saved
+            .and_then(|d| names.iter().position(|n| n == d))
+            .map_or(0, |i| i + 1);
+        Self {
+            names: names.into(),
+            selected,
+        }
+    }
+
+    /// The device a picked dropdown index stands for (`None` = default).
+    fn pick(names: &[String], index: usize) -> Option<String> {
+        index.checked_sub(1).and_then(|i| names.get(i)).cloned()
     }
 }
 
@@ -236,18 +346,18 @@ fn cache_section(
     .into_iter()
     .filter(|(f, _)| *f > 0.0)
     .collect();
-    let total_label = fmt_bytes(total);
-    let audio_label = format!("Audio  {}", fmt_bytes(usage.audio));
-    let art_label = format!("Album art  {}", fmt_bytes(usage.art));
-    let canvas_label = format!("Canvas  {}", fmt_bytes(usage.canvas));
-    let json_label = format!("Metadata  {}", fmt_bytes(usage.json));
+    let total_label = format::bytes(total);
+    let audio_label = tr_n("settings.storage.audio", format::bytes(usage.audio));
+    let art_label = tr_n("settings.storage.art", format::bytes(usage.art));
+    let canvas_label = tr_n("settings.storage.canvas", format::bytes(usage.canvas));
+    let json_label = tr_n("settings.storage.metadata", format::bytes(usage.json));
     let path = path.to_string();
     s.col(()).w(Len::Fill).gap(t::SP_2).child(move |c| {
         c.row(())
             .w(Len::Fill)
             .align(Align::Center)
             .child(|h| {
-                h.text((), "Storage", t::TEXT_SM).color(t::TEXT_DIM);
+                h.text((), tr("settings.storage"), t::TEXT_SM).color(t::TEXT_DIM);
                 h.row(())
                     .push_end()
                     .child(|e| {
@@ -290,7 +400,7 @@ fn cache_section(
             .child(move |loc| {
                 loc.col(())
                     .child(|p| {
-                        p.text((), "Location", t::TEXT_XS).color(t::TEXT_DIM);
+                        p.text((), tr("settings.location"), t::TEXT_XS).color(t::TEXT_DIM);
                         p.text((), &path, t::TEXT_XS).color(t::TEXT).max_width_px(240.0);
                     });
                 loc.row(())
@@ -303,7 +413,8 @@ fn cache_section(
                     .hover_color(t::BTN_HOVER)
                     .on_click(move |_| on_change_dir())
                     .child(|b| {
-                        b.text((), "Change\u{2026}", t::TEXT_SM).color(t::TEXT);
+                        b.text((), tr("settings.change_dir"), t::TEXT_SM)
+                            .color(t::TEXT);
                     });
             });
         // Clear — full width to match the section.
@@ -316,7 +427,8 @@ fn cache_section(
             .hover_color(t::BTN_HOVER)
             .on_click(move |_| on_clear())
             .child(|b| {
-                b.text((), "Clear cache", t::TEXT_SM).color(t::TEXT);
+                b.text((), tr("settings.clear_cache"), t::TEXT_SM)
+                    .color(t::TEXT);
             });
     });
 }
@@ -340,7 +452,7 @@ fn header(s: &mut Scene, icons: &IconSet, overlay: Overlay) {
         .w(Len::Fill)
         .align(Align::Center)
         .child(|h| {
-            h.text((), "Settings", t::TEXT_XL).color(t::TEXT);
+            h.text((), tr("settings.title"), t::TEXT_XL).color(t::TEXT);
             h.row(())
                 .push_end()
                 .w_px(t::SP_8)
@@ -363,6 +475,8 @@ fn setting_row(
     accent: &Signal<[f32; 4]>,
     on_change: Rc<dyn Fn()>,
 ) {
+    // The handlers re-read the bound signal, so the new value isn't needed.
+    let on_change: Rc<dyn Fn(bool)> = Rc::new(move |_| on_change());
     s.row(())
         .w(Len::Fill)
         .align(Align::Center)
@@ -374,95 +488,27 @@ fn setting_row(
             r.row(())
                 .push_end()
                 .align(Align::Center)
-                .child(|ctrl| toggle_switch(ctrl, state, accent, on_change));
+                .child(|ctrl| toggle_switch(ctrl, state, accent, false, on_change));
         });
 }
 
-/// The animated on/off switch. A `knob_t` signal (0..=TRAVEL px) is
-/// **seeded to the current state** at build so opening the popup shows
-/// the right position instantly — no spurious mount animation. Clicking
-/// flips the bound `state` and tweens `knob_t` via the timeline; the
-/// knob (spacer-width bind) and track colour (`Computed` over `knob_t`)
-/// both follow, so the slide + colour fade are one smooth motion with no
-/// scene rebuild. The lib bubbles a click on the knob up to this handler.
-fn toggle_switch(
-    s: &mut Scene,
-    state: &Signal<bool>,
-    accent: &Signal<[f32; 4]>,
-    on_change: Rc<dyn Fn()>,
-) {
-    let knob_t = Signal::new(if state.get() { TOGGLE_TRAVEL } else { 0.0 });
-    let track_col = Computed::new(
-        (knob_t.clone(), accent.clone()),
-        |(x, acc)| {
-            let f = (x / TOGGLE_TRAVEL).clamp(0.0, 1.0);
-            lerp4(TOGGLE_OFF, acc, f)
-        },
-    );
-    let st = state.clone();
-    let kt = knob_t.clone();
-    s.row(())
-        .w_px(TOGGLE_W)
-        .h_px(TOGGLE_H)
-        .radius(t::R_FULL)
-        .color(track_col)
-        .align(Align::Center)
-        .pad_xy(TOGGLE_PAD, 0.0)
-        .on_click(move |ctx| {
-            let now_on = !st.get();
-            st.set(now_on);
-            on_change();
-            let target = if now_on { TOGGLE_TRAVEL } else { 0.0 };
-            ctx.timeline.animate(
-                &kt,
-                target,
-                Curve::EaseInOut,
-                Duration::from_millis(TOGGLE_MS),
-                ctx.now,
-            );
-        })
-        .child(|tr| {
-            // Spacer whose width tracks `knob_t` (0 → TRAVEL), pushing the
-            // knob from the left end to the right as the tween advances.
-            tr.rect(())
-                .width_px_bind(knob_t.clone())
-                .h_px(1.0)
-                .rgba(0.0, 0.0, 0.0, 0.0);
-            tr.rect(())
-                .w_px(TOGGLE_KNOB)
-                .h_px(TOGGLE_KNOB)
-                .radius(t::R_FULL)
-                .rgba(1.0, 1.0, 1.0, 1.0);
-        });
-}
-
-/// Component-wise linear interpolation between two RGBA colours.
-fn lerp4(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
-    [
-        a[0] + (b[0] - a[0]) * t,
-        a[1] + (b[1] - a[1]) * t,
-        a[2] + (b[2] - a[2]) * t,
-        a[3] + (b[3] - a[3]) * t,
-    ]
-}
-
 fn account(s: &mut Scene, profile: Option<&Profile>, sign_out: Rc<dyn Fn()>) {
     let name = profile
-        .map(|p| p.display_name.as_str())
+        .map(|p| p.display_name.clone())
         .filter(|n| !n.is_empty())
-        .unwrap_or("Spotify account");
+        .unwrap_or_else(|| tr("settings.account.fallback"));
     s.col(())
         .w(Len::Fill)
         .gap(t::SP_2)
         .child(|acc| {
-            acc.text((), "Account", t::TEXT_SM).color(t::TEXT_DIM);
+            acc.text((), tr("settings.account"), t::TEXT_SM).color(t::TEXT_DIM);
             // Name on the left, Sign out pushed to the right edge, both
             // vertically centred on one full-width row.
             acc.row(())
                 .w(Len::Fill)
                 .align(Align::Center)
                 .child(|r| {
-                    r.text((), name, t::TEXT_BASE).color(t::TEXT);
+                    r.text((), name.as_str(), t::TEXT_BASE).color(t::TEXT);
                     r.row(())
                         .push_end()
                         .w_px(SIGN_OUT_W)
@@ -473,8 +519,45 @@ fn account(s: &mut Scene, profile: Option<&Profile>, sign_out: Rc<dyn Fn()>) {
                         .hover_color(t::BTN_HOVER)
                         .on_click(move |_| sign_out())
                         .child(|b| {
-                            b.text((), "Sign out", t::TEXT_SM).color(t::TEXT);
+                            b.text((), tr("settings.sign_out"), t::TEXT_SM).color(t::TEXT);
                         });
                 });
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listed() -> Vec<String> {
+        vec!["Speakers".to_owned(), "Headphones".to_owned()]
+    }
+
+    #[test]
+    fn default_is_the_first_option() {
+        let c = DeviceChoices::new(&listed(), None);
+        assert_eq!(c.selected, 0);
+        assert_eq!(&*c.names, listed().as_slice());
+        assert_eq!(DeviceChoices::pick(&c.names, 0), None);
+    }
+
+    #[test]
+    fn listed_device_maps_both_ways() {
+        let c = DeviceChoices::new(&listed(), Some("Headphones"));
+        assert_eq!(c.selected, 2);
+        assert_eq!(DeviceChoices::pick(&c.names, 1).as_deref(), Some("Speakers"));
+        assert_eq!(DeviceChoices::pick(&c.names, c.selected).as_deref(), Some("Headphones"));
+    }
+
+    #[test]
+    fn unplugged_saved_device_is_appended_and_selected() {
+        let c = DeviceChoices::new(&listed(), Some("USB DAC"));
+        assert_eq!(c.names.len(), 3);
+        assert_eq!(c.selected, 3);
+        assert_eq!(DeviceChoices::pick(&c.names, 3).as_deref(), Some("USB DAC"));
+        // Nothing listed at all (the host probe hasn't landed yet).
+        let c = DeviceChoices::new(&[], Some("USB DAC"));
+        assert_eq!(c.selected, 1);
+        assert_eq!(DeviceChoices::pick(&c.names, 2), None);
+    }
+}
@@ -8,11 +8,12 @@
 //! virtualised list, no collapsing header.
 
 use std::rc::Rc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use opal_gfx::{Align, ImageHandle, Justify, Len, Overflow, Scene, Signal};
 
 use crate::api::PlayTarget;
+use crate::i18n::{tr, tr_n};
 use crate::model::MenuTarget;
 use crate::views::MainNav;
 use crate::views::home::{CtxMenuFn, NavFn, PlayFn};
@@ -55,6 +56,9 @@ pub struct ShowAllGroup {
 pub struct ShowAllViewData {
     pub title: String,
     pub groups: Vec<ShowAllGroup>,
+    /// Keyboard-highlighted row, counted across all groups (search's
+    /// Up/Down selection). `None` everywhere else.
+    pub selected: Option<usize>,
 }
 
 /// Render the Show-all page into `s` (the caller's transition wrapper).
@@ -94,6 +98,7 @@ pub fn view(
                 .color(t::TEXT)
                 .max_width_px(520.0);
 
+            let mut index = 0;
             for group in &data.groups {
                 if let Some(h) = &group.header {
                     c.row(())
@@ -105,7 +110,17 @@ pub fn view(
                         });
                 }
                 for row in &group.rows {
-                    show_all_row(c, icons, row, &on_navigate, &on_play, &on_context_menu);
+                    let selected = data.selected == Some(index);
+                    index += 1;
+                    show_all_row(
+                        c,
+                        icons,
+                        row,
+                        selected,
+                        &on_navigate,
+                        &on_play,
+                        &on_context_menu,
+                    );
                 }
             }
         });
@@ -113,10 +128,12 @@ pub fn view(
 
 /// One full-width row: thumb + title/subtitle (+ trailing chevron for
 /// rows that open a detail page; play rows have no nav affordance).
+/// `selected` lifts it like a held hover (keyboard highlight).
 fn show_all_row(
     s: &mut Scene,
     icons: &Rc<IconSet>,
     row: &ShowAllRow,
+    selected: bool,
     nav: &NavFn,
     play: &PlayFn,
     on_context_menu: &CtxMenuFn,
@@ -131,6 +148,10 @@ fn show_all_row(
         .align(Align::Center)
         .radius(t::R_MD)
         .hover_color(t::HOVER_LIFT_SUBTLE);
+    if selected {
+        let c = t::HOVER_LIFT;
+        r.rgba(c[0], c[1], c[2], c[3]);
+    }
     match &row.action {
         RowAction::Open(target) => {
             let nav = nav.clone();
@@ -191,21 +212,6 @@ fn show_all_row(
         });
 }
 
-const MONTHS: [&str; 12] = [
-    "January",
-    "February",
-    "March",
-    "April",
-    "May",
-    "June",
-    "July",
-    "August",
-    "September",
-    "October",
-    "November",
-    "December",
-];
-
 /// Today's + yesterday's date as `YYYY-MM-DD` (UTC — matches Spotify's
 /// `played_at` which is UTC; good enough for day bucketing).
 pub fn today_yesterday() -> (String, String) {
@@ -222,17 +228,29 @@ pub fn today_yesterday() -> (String, String) {
 pub fn day_label(played_at: &str, today: &str, yesterday: &str) -> String {
     let date = played_at.get(..10).unwrap_or("");
     if date == today {
-        "Today".to_string()
+        tr("date.today")
     } else if date == yesterday {
-        "Yesterday".to_string()
-    } else if let Some((y, m, d)) = parse_ymd(date) {
-        let _ = y;
-        format!("{} {}", MONTHS[(m as usize).clamp(1, 12) - 1], d)
+        tr("date.yesterday")
+    } else if let Some((_, m, d)) = parse_ymd(date) {
+        tr_n(&format!("date.month.{}", m.clamp(1, 12)), d)
     } else {
-        "Earlier".to_string()
+        tr("date.earlier")
     }
 }
 
+/// How long ago a `played_at` timestamp (`2024-06-05T18:03:12.345Z`,
+/// UTC) was, for the history rows' relative label. `None` if it doesn't
+/// parse or lies in the future (clock skew).
+pub fn played_ago(played_at: &str) -> Option<Duration> {
+    let (y, m, d) = parse_ymd(played_at.get(..10)?)?;
+    let mut hms = played_at.get(11..19)?.splitn(3, ':');
+    let mut next = || hms.next()?.parse::<u64>().ok();
+    let (h, mi, s) = (next()?, next()?, next()?);
+    let days = u64::try_from(days_from_civil(y, m, d)).ok()?;
+    let at = UNIX_EPOCH + Duration::from_secs(days * 86_400 + h * 3600 + mi * 60 + s);
+    SystemTime::now().duration_since(at).ok()
+}
+
 fn parse_ymd(s: &str) -> Option<(i64, u32, u32)> {
     let mut it = s.splitn(3, '-');
     let y = it.next()?.parse().ok()?;
@@ -246,6 +264,18 @@ fn date_string(days_since_epoch: i64) -> String {
     format!("{y:04}-{m:02}-{d:02}")
 }
 
+/// (year, month, day) → days since epoch. Howard Hinnant's
+/// `days_from_civil`, the inverse of [`civil_from_days`].
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64; // [0, 399]
+    let mp = u64::from(if m > 2 { m - 3 } else { m + 9 }); // [0, 11]
+    let doy = (153 * mp + 2) / 5 + u64::from(d) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe as i64 - 719_468
+}
+
 /// Days-since-epoch → (year, month, day). Howard Hinnant's `civil_from_days`.
 fn civil_from_days(z: i64) -> (i64, u32, u32) {
     let z = z + 719_468;
@@ -259,3 +289,34 @@ fn civil_from_days(z: i64) -> (i64, u32, u32) {
     let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // [1, 12]
     (y + if m <= 2 { 1 } else { 0 }, m, d)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_dates_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        for days in [-1, 0, 59, 10_956, 19_876, 20_000] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn played_ago_reads_spotify_timestamps() {
+        assert!(played_ago("2001-09-09T01:46:40.000Z").unwrap().as_secs() > 0);
+        assert_eq!(played_ago("2999-01-01T00:00:00Z"), None);
+        assert_eq!(played_ago("yesterday"), None);
+    }
+
+    #[test]
+    fn day_labels_come_from_the_bundle() {
+        let (today, yesterday) = ("2024-06-06", "2024-06-05");
+        assert_eq!(day_label("2024-06-06T09:00:00Z", today, yesterday), "Today");
+        assert_eq!(day_label("2024-06-05T23:59:00Z", today, yesterday), "Yesterday");
+        assert_eq!(day_label("2024-02-14T12:00:00Z", today, yesterday), "February 14");
+        assert_eq!(day_label("", today, yesterday), "Earlier");
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! Reads the library's playlist list + the shared art cache, the live
 //! accent (filter chips), the current nav (row selection), and the
-//! resizable width; raises nav intents through `on_navigate`. Collapses
-//! to an icon-only rail as the splitter drags the width down.
+//! resizable width; raises nav intents through `on_navigate` and opens
+//! the playlist context menu (warm-up mark) on right-click. Collapses to
+//! an icon-only rail as the splitter drags the width down.
 
 use std::rc::Rc;
 
@@ -11,9 +12,10 @@ use opal_gfx::{Align, Computed, ImageHandle, Justify, Len, Overflow, Scene, Sign
 
 use crate::album_art;
 use crate::api::{HomeData, LIKED_SONGS_ID};
-use crate::model::ArtModel;
+use crate::i18n::tr;
+use crate::model::{ArtModel, MenuTarget};
 use crate::views::MainNav;
-use crate::views::home::NavFn;
+use crate::views::home::{CtxMenuFn, NavFn, attach_context_menu};
 use crate::widgets::chip::chip;
 use crate::widgets::component::Component;
 use crate::widgets::icon::{Icon, IconSet};
@@ -25,7 +27,11 @@ pub struct Sidebar<'a> {
     pub accent: &'a Signal<[f32; 4]>,
     pub nav: &'a MainNav,
     pub on_navigate: NavFn,
+    /// Right-click on a library row — the playlist menu.
+    pub on_context_menu: CtxMenuFn,
     pub home: &'a HomeData,
+    /// Playlist ids marked for launch warm-up (the menu item's state).
+    pub warm: &'a [String],
     /// The art model — narrow per-row lookups (`art.signal(key)`); no held
     /// `home_art` borrow.
     pub art: &'a ArtModel,
@@ -66,7 +72,7 @@ impl Component for Sidebar<'_> {
                             .on_click(move |ctx| nav(ctx, MainNav::Home))
                             .child(|hl| {
                                 icons.render(hl, Icon::Home, t::ICON_MD, t::TEXT);
-                                hl.text((), "Your Library", 14.0).color(t::TEXT);
+                                hl.text((), tr("library.title"), 14.0).color(t::TEXT);
                             });
                         h.row(()).push_end().child(|r| {
                             icons.render(r, Icon::Plus, t::ICON_MD, t::TEXT_DIM);
@@ -81,9 +87,9 @@ impl Component for Sidebar<'_> {
                     .align(Align::Center)
                     .overflow_y(Overflow::Hidden)
                     .child(|chips| {
-                        chip(chips, "Playlists", true, self.accent);
-                        chip(chips, "Artists", false, self.accent);
-                        chip(chips, "Albums", false, self.accent);
+                        chip(chips, &tr("library.filter.playlists"), true, self.accent);
+                        chip(chips, &tr("library.filter.artists"), false, self.accent);
+                        chip(chips, &tr("library.filter.albums"), false, self.accent);
                     });
                 c.col(())
                     .w(Len::Fill)
@@ -102,11 +108,12 @@ impl Component for Sidebar<'_> {
                         // Liked Songs — pinned first. Spotify doesn't surface
                         // the saved-tracks collection via /me/playlists, so
                         // it's synthesised here.
+                        let kind = tr("kind.playlist");
                         library_row(
                             c,
                             icons,
-                            "Liked Songs",
-                            "Playlist",
+                            &tr("player.liked_songs"),
+                            &kind,
                             None,
                             true,
                             nav_is(self.nav, LIKED_SONGS_ID),
@@ -116,6 +123,8 @@ impl Component for Sidebar<'_> {
                                 liked: true,
                             },
                             &self.on_navigate,
+                            self.menu_target(LIKED_SONGS_ID),
+                            &self.on_context_menu,
                         );
                         for p in &self.home.playlists {
                             // Sidebar icons use the tiny (64 px) cover tier;
@@ -129,7 +138,7 @@ impl Component for Sidebar<'_> {
                                 c,
                                 icons,
                                 &p.name,
-                                "Playlist",
+                                &kind,
                                 sig,
                                 false,
                                 nav_is(self.nav, &p.id),
@@ -139,6 +148,8 @@ impl Component for Sidebar<'_> {
                                     liked: false,
                                 },
                                 &self.on_navigate,
+                                self.menu_target(&p.id),
+                                &self.on_context_menu,
                             );
                         }
                     });
@@ -146,6 +157,17 @@ impl Component for Sidebar<'_> {
     }
 }
 
+impl Sidebar<'_> {
+    /// Context-menu target for the library row of playlist `id`.
+    fn menu_target(&self, id: &str) -> MenuTarget {
+        MenuTarget {
+            playlist_id: id.to_string(),
+            warm: self.warm.iter().any(|w| w == id),
+            ..Default::default()
+        }
+    }
+}
+
 /// Is the centre pane currently showing the playlist with this `id`?
 fn nav_is(nav: &MainNav, id: &str) -> bool {
     matches!(nav, MainNav::Playlist { id: nid, .. } if nid == id)
@@ -203,6 +225,8 @@ fn library_row(
     sidebar_w: &Signal<f32>,
     nav_target: MainNav,
     on_navigate: &NavFn,
+    menu: MenuTarget,
+    on_context_menu: &CtxMenuFn,
 ) {
     let nav = on_navigate.clone();
     let mut row = s.row(());
@@ -213,6 +237,7 @@ fn library_row(
         .align(Align::Center)
         .radius(t::R_MD)
         .on_click(move |ctx| nav(ctx, nav_target.clone()));
+    attach_context_menu(&mut row, on_context_menu, menu);
     // Selected row sits on the panel-highlight fill; others stay
     // transparent and just lift on hover.
     if selected {
@@ -0,0 +1,93 @@
+//! Toast stack — the transient notifications posted through
+//! [`ToastModel`], in the bottom-right corner above the player bar.
+//!
+//! Rendered near the top of the Home scene (only the context menu sits
+//! above it). Each toast slides in from the right and fades on its own
+//! `t` signal, so entry/exit never rebuild; the stack only rebuilds when
+//! a toast is added or removed.
+
+use std::rc::Rc;
+
+use opal_gfx::{Align, Bind, Computed, CursorIcon, Justify, Len, Scene, Signal};
+
+use crate::model::ToastModel;
+use crate::model::toast::ToastKind;
+use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::tokens as t;
+
+const TOAST_W: f32 = 320.0;
+/// Horizontal travel of the slide-in (logical px).
+const SLIDE: f32 = 24.0;
+const SUCCESS: [f32; 4] = [0.42, 0.85, 0.52, 1.0];
+const ERROR: [f32; 4] = [0.93, 0.46, 0.46, 1.0];
+
+/// Render the stack if any toast is live. `on_dismiss(id)` closes one.
+pub fn view(
+    s: &mut Scene,
+    toasts: &ToastModel,
+    icons: &IconSet,
+    accent: &Signal<[f32; 4]>,
+    on_dismiss: Rc<dyn Fn(u64)>,
+) {
+    let toasts = toasts.toasts.borrow();
+    if toasts.is_empty() {
+        return;
+    }
+    // Full-window, handler-free column: clicks fall through to the app
+    // everywhere but the toasts themselves.
+    s.col(())
+        .abs(0.0, 0.0)
+        .w(Len::Fill)
+        .h(Len::Fill)
+        .pad_xy(t::SP_4, t::PLAYER_H + t::SP_2)
+        .gap(t::SP_2)
+        .justify(Justify::End)
+        .align(Align::End)
+        .child(|stack| {
+            for toast in toasts.iter() {
+                let slide = Computed::new((toast.t.clone(),), |(tt,)| {
+                    [(1.0 - tt.clamp(0.0, 1.0)) * SLIDE, 0.0]
+                });
+                let stripe: Bind<[f32; 4]> = match toast.kind {
+                    ToastKind::Info => accent.clone().into(),
+                    ToastKind::Success => SUCCESS.into(),
+                    ToastKind::Error => ERROR.into(),
+                };
+                let id = toast.id;
+                let dismiss = on_dismiss.clone();
+                stack
+                    .row(())
+                    .w_px(TOAST_W)
+                    .pos(slide)
+                    .opacity_bind(toast.t.clone())
+                    .on_hover(toast.hovered.clone())
+                    .pad(t::SP_3)
+                    .gap(t::SP_3)
+                    .align(Align::Center)
+                    .radius(t::R_LG)
+                    .rgba(t::PANEL_HI[0], t::PANEL_HI[1], t::PANEL_HI[2], 1.0)
+                    .border(1.0, t::BORDER)
+                    .child(|r| {
+                        r.rect(())
+                            .w_px(t::SP_1)
+                            .h_px(t::SP_6)
+                            .radius(t::R_FULL)
+                            .color(stripe);
+                        r.col(()).w(Len::Fill).child(|m| {
+                            m.text((), &toast.message, t::TEXT_SM)
+                                .color(t::TEXT)
+                                .max_width_px(TOAST_W - 80.0);
+                        });
+                        r.row(())
+                            .w_px(t::SP_6)
+                            .h_px(t::SP_6)
+                            .center()
+                            .radius(t::R_FULL)
+                            .hover_color(t::BTN_HOVER)
+                            .cursor(CursorIcon::Pointer)
+                            .on_click(move |_| dismiss(id))
+                            .child(|b| icons.render(b, Icon::Close, t::ICON_SM, t::TEXT_DIM));
+                    });
+            }
+        });
+}
@@ -1,10 +1,11 @@
-//! Top chrome bar — window drag region, nav arrows, search, settings/bell,
-//! and the min/max/close window buttons. A [`Component`].
+//! Top chrome bar — window drag region, nav arrows, the search field,
+//! settings/bell, and the min/max/close window buttons. A [`Component`].
 
 use std::rc::Rc;
 
-use opal_gfx::{Align, Len, Overlay, Scene, WindowAction};
+use opal_gfx::{Align, Key, Len, Overlay, Scene, WindowAction};
 
+use crate::i18n::tr;
 use crate::widgets::chrome::chrome_btn;
 use crate::widgets::component::Component;
 use crate::widgets::icon::{Icon, IconSet};
@@ -16,6 +17,15 @@ pub struct TopBar<'a> {
     pub settings: &'a Overlay,
     /// Measure cache usage + rebuild when the modal opens.
     pub on_settings_open: Rc<dyn Fn()>,
+    /// Current search text (the field is re-seeded with it on rebuild).
+    pub search_text: String,
+    /// Field edits — debounced into a search by the frame tick.
+    pub on_search: Rc<dyn Fn(&str)>,
+    /// Enter in the field.
+    pub on_search_submit: Rc<dyn Fn()>,
+    /// Keys the field would otherwise take (Up/Down move the results
+    /// highlight); `true` consumes the key.
+    pub on_search_key: Rc<dyn Fn(Key) -> bool>,
     pub icons: &'a Rc<IconSet>,
 }
 
@@ -31,9 +41,9 @@ impl Component for TopBar<'_> {
             .rgba(0.0, 0.0, 0.0, 0.0)
             .window_action(WindowAction::DragMove)
             .child(|t_row| {
-                topbar_icon_btn(t_row, icons, Icon::Menu);
-                topbar_icon_btn(t_row, icons, Icon::ChevronLeft);
-                topbar_icon_btn(t_row, icons, Icon::ChevronRight);
+                topbar_icon_btn(t_row, icons, Icon::Menu, &tr("top.menu"));
+                topbar_icon_btn(t_row, icons, Icon::ChevronLeft, &tr("top.back"));
+                topbar_icon_btn(t_row, icons, Icon::ChevronRight, &tr("top.forward"));
 
                 t_row
                     .row(())
@@ -52,28 +62,65 @@ impl Component for TopBar<'_> {
                             .border(1.0, t::BORDER)
                             .child(|s2| {
                                 icons.render(s2, Icon::Search, t::ICON_SM, t::TEXT_DIM);
-                                s2.text((), "What do you want to play?", 13.0).color(t::TEXT_DIM);
+                                let on_search = self.on_search.clone();
+                                let on_submit = self.on_search_submit.clone();
+                                let on_key = self.on_search_key.clone();
+                                // Named: the field keeps its caret + focus
+                                // across the rebuilds typing triggers.
+                                s2.text_field("search_field", &self.search_text, 13.0)
+                                    .placeholder(&tr("top.search_placeholder"))
+                                    .w(Len::Fill)
+                                    .h(Len::Fill)
+                                    .align(Align::Center)
+                                    .text_color(t::TEXT)
+                                    .placeholder_color(t::TEXT_DIM)
+                                    .on_change(move |v: &str| on_search(v))
+                                    .on_submit(move |_| on_submit())
+                                    .on_key(move |key| on_key(key));
                             });
                     });
 
                 let settings = self.settings.clone();
                 let on_settings_open = self.on_settings_open.clone();
-                topbar_icon_btn_click(t_row, icons, Icon::Settings, move |ctx| {
-                    settings.open(ctx.timeline, ctx.now);
-                    on_settings_open();
-                });
-                topbar_icon_btn(t_row, icons, Icon::Bell);
+                topbar_icon_btn_click(
+                    t_row,
+                    icons,
+                    Icon::Settings,
+                    &tr("top.settings"),
+                    move |ctx| {
+                        settings.open(ctx.timeline, ctx.now);
+                        on_settings_open();
+                    },
+                );
+                topbar_icon_btn(t_row, icons, Icon::Bell, &tr("top.notifications"));
 
-                chrome_btn(t_row, icons, Icon::Minimize, WindowAction::Minimize, t::BTN_HOVER, true);
+                chrome_btn(
+                    t_row,
+                    icons,
+                    Icon::Minimize,
+                    &tr("window.minimize"),
+                    WindowAction::Minimize,
+                    t::BTN_HOVER,
+                    true,
+                );
                 chrome_btn(
                     t_row,
                     icons,
                     Icon::Maximize,
+                    &tr("window.maximize"),
                     WindowAction::ToggleMaximize,
                     t::BTN_HOVER,
                     false,
                 );
-                chrome_btn(t_row, icons, Icon::Close, WindowAction::Close, t::CLOSE_HOVER, false);
+                chrome_btn(
+                    t_row,
+                    icons,
+                    Icon::Close,
+                    &tr("window.close"),
+                    WindowAction::Close,
+                    t::CLOSE_HOVER,
+                    false,
+                );
                 // ^ window controls share the canonical widget in `chrome`.
             });
     }
@@ -81,11 +128,12 @@ impl Component for TopBar<'_> {
 
 /// Top-bar pill button with a click handler (e.g. the settings gear). The
 /// handler receives the full `EventCtx` so it can start a timeline tween
-/// (the settings fade) at click time.
+/// (the settings fade) at click time. `hint` is the hover tooltip.
 fn topbar_icon_btn_click(
     s: &mut Scene,
     icons: &IconSet,
     icon: Icon,
+    hint: &str,
     on_click: impl Fn(&mut opal_gfx::EventCtx) + 'static,
 ) {
     s.row(())
@@ -95,13 +143,16 @@ fn topbar_icon_btn_click(
         .hover_color(t::PANEL_HI)
         .radius(t::R_FULL)
         .center()
+        .hover_hint(hint)
         .on_click(on_click)
         .child(|c| {
             icons.render(c, icon, t::ICON_MD, t::TEXT);
         });
 }
 
-fn topbar_icon_btn(s: &mut Scene, icons: &IconSet, icon: Icon) {
+/// Top-bar pill button with no action yet (the bell). `hint` is the
+/// hover tooltip.
+fn topbar_icon_btn(s: &mut Scene, icons: &IconSet, icon: Icon, hint: &str) {
     s.row(())
         .w_px(t::TOPBAR_BTN)
         .h_px(t::TOPBAR_BTN)
@@ -109,6 +160,7 @@ fn topbar_icon_btn(s: &mut Scene, icons: &IconSet, icon: Icon) {
         .hover_color(t::PANEL_HI)
         .radius(t::R_FULL)
         .center()
+        .hover_hint(hint)
         .child(|c| {
             icons.render(c, icon, t::ICON_MD, t::TEXT);
         });
@@ -15,12 +15,14 @@
 use std::cell::Cell;
 use std::rc::Rc;
 
-use opal_gfx::{Align, Computed, EventCtx, Len, Scene};
+use opal_gfx::{Align, Computed, EventCtx, Len, Scene, Signal};
 
 use crate::app::AppState;
+use crate::i18n::tr;
 use crate::views::View;
 use crate::widgets::button::{ButtonTone, pill_button};
 use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::spinner::spinner;
 use crate::widgets::{chrome, tokens};
 use crate::worker::Worker;
 
@@ -78,11 +80,11 @@ impl LoginView {
                     .child(|body| {
                         // Top-left: back to setup — only when we came from it.
                         if show_back {
-                            body.row(()).w(Len::Fill).h(Len::Auto).pad(16.0).child(|tr| {
+                            body.row(()).w(Len::Fill).h(Len::Auto).pad(16.0).child(|top| {
                                 pill_button(
-                                    tr,
+                                    top,
                                     &self.icons,
-                                    "Back",
+                                    &tr("login.back"),
                                     Some(Icon::ChevronLeft),
                                     ButtonTone::Neutral,
                                     on_back,
@@ -91,30 +93,39 @@ impl LoginView {
                         }
 
                         // Centre fills the gap between the corners → title +
-                        // button sit dead-centre. Entrance fade only (a slide
-                        // here would need an abs wrapper, which would drop the
-                        // corners out of flow). `h(Fill)` pushes Reset to the
-                        // bottom.
-                        let fade = Computed::new(
-                            (state.router.view_t.clone(),),
-                            |(tt,)| tt.clamp(0.0, 1.0),
-                        );
+                        // button sit dead-centre. `h(Fill)` pushes Reset to
+                        // the bottom. The entrance is staggered off `view_t`
+                        // (see `ENTRANCE`): the logo never fades, so the
+                        // handoff from the splash (same mark, same spot)
+                        // doesn't blink; the tagline and then the action row
+                        // rise in under it.
+                        let t = &state.router.view_t;
                         body.col(())
                             .w(Len::Fill)
                             .h(Len::Fill)
                             .center()
                             .gap(20.0)
-                            .opacity_bind(fade)
                             .child(|c| {
                                 logo_title(c, &self.icons);
-                                c.text((), "An unofficial Spotify desktop client.", tokens::TEXT_BASE)
-                                    .color(tokens::TEXT_DIM);
-                                if checking {
-                                    c.text((), "Checking saved credentials...", tokens::TEXT_SM)
-                                        .color(tokens::TEXT_DIM);
-                                } else {
-                                    login_button(c, on_login);
-                                }
+                                c.col(())
+                                    .align(Align::Center)
+                                    .pos(rise(t, ENTRANCE.tagline))
+                                    .opacity_bind(beat(t, ENTRANCE.tagline))
+                                    .child(|tg| {
+                                        tg.text((), tr("app.tagline"), tokens::TEXT_BASE)
+                                            .color(tokens::TEXT_DIM);
+                                    });
+                                c.col(())
+                                    .align(Align::Center)
+                                    .pos(rise(t, ENTRANCE.action))
+                                    .opacity_bind(beat(t, ENTRANCE.action))
+                                    .child(|a| {
+                                        if checking {
+                                            checking_status(a, &state);
+                                        } else {
+                                            login_button(a, on_login);
+                                        }
+                                    });
                             });
 
                         // Bottom-left: destructive reset (hidden mid-check).
@@ -123,7 +134,7 @@ impl LoginView {
                                 pill_button(
                                     br,
                                     &self.icons,
-                                    "Reset preferences",
+                                    &tr("login.reset"),
                                     None,
                                     ButtonTone::Danger,
                                     on_reset,
@@ -170,6 +181,41 @@ impl LoginHandle {
     }
 }
 
+/// One step of the entrance: the `view_t` window (start, end) it plays in.
+type Beat = (f32, f32);
+
+/// The centre column's entrance, in order. Beats overlap so the sequence
+/// reads as one motion rather than three separate fades; retune here, the
+/// build only looks them up.
+struct Entrance {
+    tagline: Beat,
+    action: Beat,
+}
+
+const ENTRANCE: Entrance = Entrance {
+    tagline: (0.0, 0.6),
+    action: (0.35, 1.0),
+};
+
+/// Rise distance for a beat's content (logical px).
+const RISE: f32 = 12.0;
+
+/// `view_t` remapped to a beat's own 0 → 1.
+fn beat(t: &Signal<f32>, (from, to): Beat) -> Computed<f32> {
+    Computed::new((t.clone(),), move |(tt,)| beat_progress(tt, from, to))
+}
+
+/// Offset that eases a beat's content up into place.
+fn rise(t: &Signal<f32>, (from, to): Beat) -> Computed<[f32; 2]> {
+    Computed::new((t.clone(),), move |(tt,)| {
+        [0.0, (1.0 - beat_progress(tt, from, to)) * RISE]
+    })
+}
+
+fn beat_progress(t: f32, from: f32, to: f32) -> f32 {
+    ((t - from) / (to - from)).clamp(0.0, 1.0)
+}
+
 /// The brand header — the logo mark beside the "Opal" wordmark, centered.
 /// (Static for now; a fluttering-wings animation is planned.)
 fn logo_title(c: &mut Scene, icons: &IconSet) {
@@ -182,6 +228,15 @@ fn logo_title(c: &mut Scene, icons: &IconSet) {
         });
 }
 
+/// Splash status line: a spinner beside "Checking saved credentials...".
+fn checking_status(c: &mut Scene, state: &AppState) {
+    c.row(()).align(Align::Center).gap(tokens::SP_2).child(|r| {
+        spinner(r, &state.spin, tokens::ICON_SM, tokens::TEXT_DIM);
+        r.text((), tr("login.checking"), tokens::TEXT_SM)
+            .color(tokens::TEXT_DIM);
+    });
+}
+
 /// The "Log in with Spotify" pill.
 fn login_button(c: &mut Scene, on_login: impl Fn(&mut EventCtx) + 'static) {
     c.row(())
@@ -193,7 +248,23 @@ fn login_button(c: &mut Scene, on_login: impl Fn(&mut EventCtx) + 'static) {
         .center()
         .on_click(on_login)
         .child(|b| {
-            b.text((), "Log in with Spotify", tokens::TEXT_BASE)
+            b.text((), tr("login.button"), tokens::TEXT_BASE)
                 .color([1.0, 1.0, 1.0, 1.0]);
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beats_play_in_order_and_settle() {
+        let (from, to) = ENTRANCE.action;
+        assert_eq!(beat_progress(0.0, from, to), 0.0);
+        assert!(beat_progress(0.5, ENTRANCE.tagline.0, ENTRANCE.tagline.1) > 0.5);
+        assert!(beat_progress(0.5, from, to) < 0.5);
+        // Both park fully shown once `view_t` does.
+        assert_eq!(beat_progress(1.0, ENTRANCE.tagline.0, ENTRANCE.tagline.1), 1.0);
+        assert_eq!(beat_progress(1.0, from, to), 1.0);
+    }
+}
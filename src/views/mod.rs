@@ -5,9 +5,13 @@
 //! layer that replaces "`main` composes everything" — `main` only
 //! constructs the views and dispatches to them.
 
+#[cfg(debug_assertions)]
+pub mod gallery;
 pub mod home;
 pub mod login;
 pub mod setup;
+#[cfg(debug_assertions)]
+pub mod stress;
 
 /// Which top-level view is mounted.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -19,6 +23,13 @@ pub enum View {
     Setup,
     Login,
     Home,
+    /// Debug-only synthetic layout stress scene (`--stress`, see
+    /// [`stress`]).
+    #[cfg(debug_assertions)]
+    Stress,
+    /// Debug-only widget gallery (`--gallery`, see [`gallery`]).
+    #[cfg(debug_assertions)]
+    Gallery,
 }
 
 /// What the centre (main) pane of the Home view is showing. The sidebar,
@@ -46,6 +57,11 @@ pub enum MainNav {
     /// The active device's play queue (now playing + next up). Fetched
     /// fresh on every open — live state, no cache.
     Queue,
+    /// Lyrics for the playing track, synced to playback where available.
+    Lyrics,
+    /// Results for the top-bar search field (tracks, artists, albums).
+    /// Opened by typing; the query itself lives in the search model.
+    Search,
 }
 
 impl MainNav {
@@ -59,9 +75,12 @@ impl MainNav {
             MainNav::Playlist { id, .. } | MainNav::Album { id } => {
                 Some(format!("detail_scroll:{id}"))
             }
-            MainNav::Home | MainNav::Artist { .. } | MainNav::ShowAll { .. } | MainNav::Queue => {
-                None
-            }
+            MainNav::Home
+            | MainNav::Artist { .. }
+            | MainNav::ShowAll { .. }
+            | MainNav::Queue
+            | MainNav::Lyrics
+            | MainNav::Search => None,
         }
     }
 }
@@ -17,17 +17,18 @@ use opal_gfx::{
 
 use crate::app::AppState;
 use crate::constants::SPOTIFY_REDIRECT_URI;
+use crate::i18n::tr;
 use crate::views::View;
 use crate::widgets::icon::IconSet;
 use crate::widgets::{chrome, tokens};
 
-/// Dashboard setup steps, shown in order on the setup card.
+/// Dashboard setup steps (string keys), shown in order on the setup card.
 const INSTRUCTIONS: &[&str] = &[
-    "1.  Open developer.spotify.com/dashboard and log in.",
-    "2.  Click \"Create app\". Name and description can be anything.",
-    "3.  Add the redirect URI below, and tick the \"Web API\" scope.",
-    "4.  Save, then open the app's Settings.",
-    "5.  Copy the \"Client ID\" and paste it here.",
+    "setup.step1",
+    "setup.step2",
+    "setup.step3",
+    "setup.step4",
+    "setup.step5",
 ];
 
 /// The Setup view controller — owns the client-id-save callback and the
@@ -63,15 +64,16 @@ pub struct SetupView {
 /// client id is exactly 32 hexadecimal characters; this catches the common
 /// paste mistakes (whitespace, a partial paste, a pasted URL, wrong length)
 /// before we navigate to login. Returns the normalised (lowercased) id, or
-/// an error message to show inline. True liveness is proven by the actual
-/// Spotify login, which rejects an unregistered id with "INVALID_CLIENT".
+/// the [`tr`] key of an error message to show inline. True liveness is
+/// proven by the actual Spotify login, which rejects an unregistered id
+/// with "INVALID_CLIENT".
 fn validate_client_id(raw: &str) -> Result<String, &'static str> {
     let id = raw.trim();
     if id.is_empty() {
-        return Err("Enter your Client ID.");
+        return Err("setup.error_empty");
     }
     if id.len() != 32 || !id.bytes().all(|b| b.is_ascii_hexdigit()) {
-        return Err("That doesn't look like a Client ID — it should be 32 letters/numbers.");
+        return Err("setup.error_format");
     }
     Ok(id.to_ascii_lowercase())
 }
@@ -84,7 +86,7 @@ impl SetupView {
             rebuild,
             draft: Rc::new(RefCell::new(String::new())),
             copied: Signal::new(0.0),
-            copy_label: TextSignal::new("Click to copy"),
+            copy_label: TextSignal::new(&tr("setup.click_to_copy")),
             error: TextSignal::new(""),
             field_id: Rc::new(Cell::new(None)),
         }
@@ -144,7 +146,7 @@ impl SetupView {
                             self.icons.render_logo(r, 56.0);
                             r.text((), "Opal", tokens::TEXT_4XL).color(tokens::TEXT);
                         });
-                        c.text((), "An unofficial Spotify desktop client.", tokens::TEXT_BASE)
+                        c.text((), tr("app.tagline"), tokens::TEXT_BASE)
                             .color(tokens::TEXT_DIM);
 
                         card(
@@ -189,8 +191,8 @@ impl SetupHandle {
         // dead login screen.
         let id = match validate_client_id(raw) {
             Ok(id) => id,
-            Err(msg) => {
-                self.error.set(msg);
+            Err(key) => {
+                self.error.set(&tr(key));
                 // A mouse click on Save moved focus to the button; put it
                 // back on the field so the caret returns + they can fix it.
                 if let Some(id) = self.field_id.get() {
@@ -232,23 +234,19 @@ fn card(
         .radius(tokens::R_2XL)
         .border(1.0, [1.0, 1.0, 1.0, 0.08])
         .child(|card| {
-            card.text((), "Set up your Spotify client id", tokens::TEXT_LG)
+            card.text((), tr("setup.title"), tokens::TEXT_LG)
                 .color(tokens::TEXT);
-            card.text(
-                (),
-                "Opal needs a Spotify app of your own to sign in. It takes a minute:",
-                tokens::TEXT_SM,
-            )
-            .color(tokens::TEXT_DIM);
+            card.text((), tr("setup.intro"), tokens::TEXT_SM)
+                .color(tokens::TEXT_DIM);
 
-            for line in INSTRUCTIONS {
-                card.text((), *line, tokens::TEXT_SM).color(tokens::TEXT_DIM);
+            for key in INSTRUCTIONS {
+                card.text((), tr(key), tokens::TEXT_SM).color(tokens::TEXT_DIM);
             }
 
             // The redirect URI must match the registered value exactly or
             // Spotify rejects the callback — call it out on its own line,
             // as a click-to-copy pill so the user can paste it verbatim.
-            card.text((), "Redirect URI (must match exactly):", tokens::TEXT_SM)
+            card.text((), tr("setup.redirect_label"), tokens::TEXT_SM)
                 .color(tokens::TEXT_DIM);
             // Click-to-copy pill. On click it flashes green + flips the hint
             // to "Copied!" instantly, then resets after ~1.8s — driven by the
@@ -260,6 +258,7 @@ fn card(
             let copied_fg = copied.clone();
             let copied_click = copied.clone();
             let label_drv = copy_label.clone();
+            let (copied_text, copy_text) = (tr("setup.copied"), tr("setup.click_to_copy"));
             card.row(())
                 .w(Len::Fill)
                 .h_px(36.0)
@@ -297,14 +296,14 @@ fn card(
                         .push_end()
                         .color(Computed::new((copied_fg,), move |(t,)| {
                             let on = t > ON;
-                            label_drv.set(if on { "Copied!" } else { "Click to copy" });
+                            label_drv.set(if on { &copied_text } else { &copy_text });
                             if on { GREEN } else { tokens::TEXT_DIM }
                         }));
                 });
 
             let mut field = card.text_field((), "", tokens::TEXT_BASE);
             field
-                .placeholder("Paste your Client ID")
+                .placeholder(&tr("setup.placeholder"))
                 .w(Len::Fill)
                 .h_px(44.0)
                 .pad_xy(12.0, 0.0)
@@ -334,7 +333,7 @@ fn card(
                 .center()
                 .on_click(on_click)
                 .child(|b| {
-                    b.text((), "Save & continue", tokens::TEXT_BASE)
+                    b.text((), tr("setup.save"), tokens::TEXT_BASE)
                         .color([1.0, 1.0, 1.0, 1.0]);
                 });
         });
@@ -0,0 +1,175 @@
+//! Debug-only **layout stress** view — a synthetic scene with thousands of
+//! nodes, so layout / batching / hover work has a repeatable target instead
+//! of "scroll a big playlist and eyeball it".
+//!
+//! Launch a debug build with `--stress <containers>,<renderables>,<pct>`
+//! (e.g. `--stress 200,5000,25`): `containers` rows, `renderables` small
+//! hoverable rects dealt across them round-robin, and `pct`% of those bound
+//! to one ping-pong opacity tween so every frame re-batches an animated
+//! subset. The view skips token load + auth entirely, so nothing routes
+//! away from it. Compiled out of release builds.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use opal_gfx::{Curve, Len, Scene, Signal, Timeline};
+
+use crate::widgets::tokens as t;
+
+/// Edge length of one synthetic renderable (logical px).
+const CELL: f32 = 6.0;
+
+/// Parsed `--stress` spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StressConfig {
+    pub containers: usize,
+    pub renderables: usize,
+    /// Share of renderables bound to the animated opacity tween, 0..=100.
+    pub animated_pct: u8,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            containers: 100,
+            renderables: 2000,
+            animated_pct: 10,
+        }
+    }
+}
+
+impl StressConfig {
+    /// `--stress` (defaults) or `--stress N,M,P` / `--stress=N,M,P` from the
+    /// process args. `None` when the flag is absent.
+    pub fn from_args() -> Option<Self> {
+        let mut args = std::env::args().skip(1);
+        while let Some(a) = args.next() {
+            if let Some(spec) = a.strip_prefix("--stress=") {
+                return Some(Self::parse(spec));
+            }
+            if a == "--stress" {
+                return Some(args.next().map(|s| Self::parse(&s)).unwrap_or_default());
+            }
+        }
+        None
+    }
+
+    /// Parse `N,M,P`; a missing or malformed field keeps its default, so
+    /// `--stress 500` means "500 containers, default everything else".
+    fn parse(spec: &str) -> Self {
+        let mut cfg = Self::default();
+        let mut fields = spec.split(',').map(|f| f.trim().parse::<usize>().ok());
+        if let Some(Some(n)) = fields.next() {
+            cfg.containers = n.max(1);
+        }
+        if let Some(Some(m)) = fields.next() {
+            cfg.renderables = m;
+        }
+        if let Some(Some(p)) = fields.next() {
+            cfg.animated_pct = p.min(100) as u8;
+        }
+        cfg
+    }
+}
+
+pub struct StressView {
+    cfg: StressConfig,
+    /// Shared opacity driven by a ping-pong tween; the animated subset binds it.
+    pulse: Signal<f32>,
+    started: Cell<bool>,
+}
+
+impl StressView {
+    pub fn new(cfg: StressConfig) -> Self {
+        log::info!(
+            "stress view: {} containers, {} renderables, {}% animated",
+            cfg.containers,
+            cfg.renderables,
+            cfg.animated_pct
+        );
+        Self {
+            cfg,
+            pulse: Signal::new(1.0),
+            started: Cell::new(false),
+        }
+    }
+
+    /// Arm the ping-pong once; the timeline then drives it every frame.
+    pub fn tick(&self, tl: &mut Timeline, now: Instant) {
+        if !self.started.replace(true) {
+            tl.animate_pingpong(
+                &self.pulse,
+                1.0,
+                0.2,
+                Curve::EaseInOut,
+                Duration::from_millis(800),
+                now,
+            );
+        }
+    }
+
+    pub fn build(&self, s: &mut Scene) {
+        let StressConfig {
+            containers,
+            renderables,
+            animated_pct,
+        } = self.cfg;
+        // Every `stride`-th renderable animates — spreads the animated
+        // subset evenly instead of clumping it into the first rows.
+        let animated = renderables * usize::from(animated_pct) / 100;
+        let stride = if animated == 0 { usize::MAX } else { renderables / animated };
+        s.col("stress_root")
+            .fill()
+            .pad(t::SP_4)
+            .gap(2.0)
+            .rgba(t::BG[0], t::BG[1], t::BG[2], 1.0)
+            .scroll_y()
+            .child(|root| {
+                for c in 0..containers {
+                    root.row(()).w(Len::Fill).gap(2.0).child(|row| {
+                        // Round-robin: container `c` holds renderables
+                        // c, c + containers, c + 2·containers, …
+                        for i in (c..renderables).step_by(containers) {
+                            let hue = i as f32 / renderables.max(1) as f32;
+                            let mut cell = row.rect(());
+                            cell.w_px(CELL)
+                                .h_px(CELL)
+                                .radius(1.0)
+                                .rgba(0.3 + 0.6 * hue, 0.5, 0.9 - 0.6 * hue, 1.0)
+                                .hover_color(t::TEXT);
+                            if i % stride == 0 {
+                                cell.opacity_bind(self.pulse.clone());
+                            }
+                        }
+                    });
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_defaults_for_missing_fields() {
+        let d = StressConfig::default();
+        assert_eq!(StressConfig::parse(""), d);
+        assert_eq!(
+            StressConfig::parse("500"),
+            StressConfig {
+                containers: 500,
+                ..d
+            }
+        );
+        assert_eq!(
+            StressConfig::parse("0,10000,250"),
+            StressConfig {
+                containers: 1,
+                renderables: 10_000,
+                animated_pct: 100,
+            }
+        );
+        assert_eq!(StressConfig::parse("x,12"), StressConfig { renderables: 12, ..d });
+    }
+}
@@ -1,5 +1,6 @@
 use opal_gfx::{Align, Len, Scene, WindowAction};
 
+use crate::i18n::tr;
 use crate::widgets::icon::{Icon, IconSet};
 use crate::widgets::tokens;
 
@@ -15,9 +16,33 @@ pub fn title_bar(s: &mut Scene, icons: &IconSet, title: &str) {
         .child(|t| {
             t.text((), title, 13.0).color(tokens::TEXT_DIM);
 
-            chrome_btn(t, icons, Icon::Minimize, WindowAction::Minimize, tokens::BTN_HOVER, true);
-            chrome_btn(t, icons, Icon::Maximize, WindowAction::ToggleMaximize, tokens::BTN_HOVER, false);
-            chrome_btn(t, icons, Icon::Close, WindowAction::Close, tokens::CLOSE_HOVER, false);
+            chrome_btn(
+                t,
+                icons,
+                Icon::Minimize,
+                &tr("window.minimize"),
+                WindowAction::Minimize,
+                tokens::BTN_HOVER,
+                true,
+            );
+            chrome_btn(
+                t,
+                icons,
+                Icon::Maximize,
+                &tr("window.maximize"),
+                WindowAction::ToggleMaximize,
+                tokens::BTN_HOVER,
+                false,
+            );
+            chrome_btn(
+                t,
+                icons,
+                Icon::Close,
+                &tr("window.close"),
+                WindowAction::Close,
+                tokens::CLOSE_HOVER,
+                false,
+            );
         });
 }
 
@@ -25,11 +50,12 @@ pub fn title_bar(s: &mut Scene, icons: &IconSet, title: &str) {
 /// source of truth for window-control chrome — reused by both the login
 /// title bar and the Home top bar so they stay visually identical.
 /// `push_end` shoves the button (and the trailing siblings that follow it)
-/// to the right edge.
+/// to the right edge. `hint` is the hover tooltip.
 pub fn chrome_btn(
     s: &mut Scene,
     icons: &IconSet,
     icon: Icon,
+    hint: &str,
     action: WindowAction,
     hover: [f32; 4],
     push_end: bool,
@@ -41,6 +67,7 @@ pub fn chrome_btn(
         .hover_color(hover)
         .radius(tokens::R_MD)
         .center()
+        .hover_hint(hint)
         .window_action(action);
     if push_end {
         b.push_end();
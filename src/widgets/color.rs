@@ -6,17 +6,28 @@
 //! Everything here keeps that accent usable: WCAG-style luminance and
 //! contrast math, a chooser that picks the most usable extracted variant,
 //! and a lift that brightens a failing accent until icons/pills tinted
-//! with it clear a minimum contrast over the chrome.
+//! with it clear a minimum contrast over the chrome. It also carries the
+//! OKLCH round trip behind [`mix_oklch`], the perceptual blend the accent
+//! tween uses so a re-tint doesn't wash through grey.
+
+use std::f32::consts::{PI, TAU};
 
 use opal_gfx::{Computed, Signal};
 
+/// sRGB transfer function, decoded: gamma-encoded channel → linear.
+fn to_linear(u: f32) -> f32 {
+    if u <= 0.04045 { u / 12.92 } else { ((u + 0.055) / 1.055).powf(2.4) }
+}
+
+/// sRGB transfer function, encoded: linear channel → gamma-encoded.
+fn from_linear(u: f32) -> f32 {
+    if u <= 0.003_130_8 { u * 12.92 } else { 1.055 * u.powf(1.0 / 2.4) - 0.055 }
+}
+
 /// WCAG relative luminance of an sRGB colour (alpha ignored): channels
 /// are linearized then weighted (Rec. 709). 0 = black, 1 = white.
 pub fn luminance(c: [f32; 4]) -> f32 {
-    fn lin(u: f32) -> f32 {
-        if u <= 0.04045 { u / 12.92 } else { ((u + 0.055) / 1.055).powf(2.4) }
-    }
-    0.2126 * lin(c[0]) + 0.7152 * lin(c[1]) + 0.0722 * lin(c[2])
+    0.2126 * to_linear(c[0]) + 0.7152 * to_linear(c[1]) + 0.0722 * to_linear(c[2])
 }
 
 /// WCAG contrast ratio between two relative luminances (1..=21).
@@ -25,6 +36,16 @@ pub fn contrast(l1: f32, l2: f32) -> f32 {
     (hi + 0.05) / (lo + 0.05)
 }
 
+/// WCAG contrast ratio between two colours (1..=21).
+pub fn contrast_ratio(a: [f32; 4], b: [f32; 4]) -> f32 {
+    contrast(luminance(a), luminance(b))
+}
+
+/// `c` with its alpha replaced.
+pub fn with_alpha(c: [f32; 4], a: f32) -> [f32; 4] {
+    [c[0], c[1], c[2], a]
+}
+
 /// Relative luminance of the dark chrome accent-tinted elements sit on:
 /// the player bar / panels — near-black with a slight backdrop bleed.
 /// Deliberately a touch above true black so the lift aims high enough to
@@ -89,8 +110,7 @@ pub fn accent_fg(accent: &Signal<[f32; 4]>) -> Computed<[f32; 4]> {
     Computed::new((accent.clone(),), |(a,)| {
         const LIGHT: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
         const DARK: [f32; 4] = [0.08, 0.08, 0.08, 1.0];
-        let l = luminance(a);
-        if contrast(l, luminance(LIGHT)) >= contrast(l, luminance(DARK)) {
+        if contrast_ratio(a, LIGHT) >= contrast_ratio(a, DARK) {
             LIGHT
         } else {
             DARK
@@ -98,6 +118,63 @@ pub fn accent_fg(accent: &Signal<[f32; 4]>) -> Computed<[f32; 4]> {
     })
 }
 
+/// Component-wise linear interpolation between two RGBA colours.
+pub fn lerp4(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+/// sRGB → OKLCH: `[lightness 0..=1, chroma, hue radians]` (alpha dropped).
+/// Björn Ottosson's OKLab, in polar form.
+pub fn to_oklch(c: [f32; 4]) -> [f32; 3] {
+    let [r, g, b] = [to_linear(c[0]), to_linear(c[1]), to_linear(c[2])];
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    let ok_l = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+    let ok_a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+    let ok_b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+    [ok_l, ok_a.hypot(ok_b), ok_b.atan2(ok_a)]
+}
+
+/// OKLCH → sRGB with alpha `a`. Out-of-gamut results are clipped per
+/// channel.
+pub fn from_oklch([ok_l, chroma, hue]: [f32; 3], a: f32) -> [f32; 4] {
+    let (ok_a, ok_b) = (chroma * hue.cos(), chroma * hue.sin());
+    let l = (ok_l + 0.396_337_78 * ok_a + 0.215_803_76 * ok_b).powi(3);
+    let m = (ok_l - 0.105_561_346 * ok_a - 0.063_854_17 * ok_b).powi(3);
+    let s = (ok_l - 0.089_484_18 * ok_a - 1.291_485_5 * ok_b).powi(3);
+    let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+    let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+    let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+    let enc = |u: f32| from_linear(u.clamp(0.0, 1.0));
+    [enc(r), enc(g), enc(b), a]
+}
+
+/// Perceptual blend from `a` to `b` at `t`: lightness and chroma lerp in
+/// OKLCH and the hue takes the short way round, so a red → green accent
+/// passes through a saturated amber rather than the muddy brown a
+/// channel-wise [`lerp4`] gives. A near-grey end has no meaningful hue
+/// and borrows the other end's. Alpha lerps linearly.
+pub fn mix_oklch(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    /// Chroma below which a colour counts as grey.
+    const ACHROMATIC: f32 = 0.02;
+    let [la, ca, mut ha] = to_oklch(a);
+    let [lb, cb, mut hb] = to_oklch(b);
+    if ca < ACHROMATIC {
+        ha = hb;
+    } else if cb < ACHROMATIC {
+        hb = ha;
+    }
+    let dh = (hb - ha + PI).rem_euclid(TAU) - PI;
+    let lch = [la + (lb - la) * t, ca + (cb - ca) * t, ha + dh * t];
+    from_oklch(lch, a[3] + (b[3] - a[3]) * t)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +238,20 @@ mod tests {
         assert!(out[0] > out[1] && out[0] > out[2]); // still red-dominant
     }
 
+    #[test]
+    fn oklch_round_trips_and_mix_keeps_chroma() {
+        for c in [[0.9, 0.2, 0.2, 1.0], [0.114, 0.725, 0.329, 1.0], [0.5, 0.5, 0.5, 0.4]] {
+            let back = from_oklch(to_oklch(c), c[3]);
+            assert!(c.iter().zip(back).all(|(x, y)| (x - y).abs() < 1e-3), "{c:?} → {back:?}");
+        }
+        let (red, green) = ([0.9, 0.1, 0.1, 1.0], [0.1, 0.8, 0.2, 1.0]);
+        let start = mix_oklch(red, green, 0.0);
+        assert!(red.iter().zip(start).all(|(x, y)| (x - y).abs() < 1e-3));
+        // Midpoint stays saturated where the channel lerp greys out.
+        let chroma = |c| to_oklch(c)[1];
+        assert!(chroma(mix_oklch(red, green, 0.5)) > chroma(lerp4(red, green, 0.5)) * 1.3);
+    }
+
     #[test]
     fn accent_fg_white_on_dark_dark_on_light() {
         let acc = Signal::new([0.1, 0.1, 0.3, 1.0]);
@@ -0,0 +1,120 @@
+//! Select-one dropdown: a field showing the current choice that expands
+//! into an option list on click.
+//!
+//! Open state lives with the caller (like the other popups): `on_toggle`
+//! flips it and requests a rebuild, so a closed dropdown costs one row.
+//! The list expands in flow beneath the field rather than floating, so it
+//! can never be clipped by (or paint under) the scroll container it sits
+//! in; past [`MAX_VISIBLE`] options it scrolls.
+//!
+//! Mouse only for now. opal-gfx delivers keys to a focused text field
+//! (`text_field(..).on_key`, as the search box uses), and nothing in a
+//! dropdown takes focus. Up/Down/Enter/Escape on the open list are a
+//! follow-up for when plain nodes can hold keyboard focus.
+
+use std::rc::Rc;
+
+use opal_gfx::{Align, Len, Scene, Signal};
+
+use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::tokens as t;
+
+/// Options shown before the list scrolls.
+const MAX_VISIBLE: usize = 6;
+/// Height of one option row (logical px).
+const OPTION_H: f32 = t::SP_9;
+
+pub struct Dropdown<'a> {
+    pub options: &'a [&'a str],
+    /// Index into `options` of the current choice.
+    pub selected: usize,
+    pub open: bool,
+    /// Live accent — tints the open field's border and the selected check.
+    pub accent: &'a Signal<[f32; 4]>,
+    /// Open/close request (field click, or after a pick).
+    pub on_toggle: Rc<dyn Fn()>,
+    /// A new option was picked — receives its index. Not called when the
+    /// already-selected option is clicked.
+    pub on_select: Rc<dyn Fn(usize)>,
+}
+
+impl Dropdown<'_> {
+    pub fn view(&self, s: &mut Scene, icons: &IconSet) {
+        let current = self.options.get(self.selected).copied().unwrap_or("");
+        s.col(()).w(Len::Fill).gap(t::SP_1).child(|c| {
+            let toggle = self.on_toggle.clone();
+            let mut field = c.row(());
+            field
+                .w(Len::Fill)
+                .h_px(t::CHIP_H)
+                .pad_xy(t::SP_3, t::SP_0)
+                .align(Align::Center)
+                .radius(t::R_MD)
+                .color(t::PANEL_HI)
+                .hover_opacity(0.85)
+                .on_click(move |_| toggle())
+                .child(|f| {
+                    f.text((), current, t::TEXT_SM).color(t::TEXT);
+                    // Expand/collapse affordance, not "navigate": down while
+                    // closed, up while the list hangs open beneath.
+                    let chevron = if self.open {
+                        Icon::ChevronUp
+                    } else {
+                        Icon::ChevronDown
+                    };
+                    f.row(()).push_end().child(|end| {
+                        icons.render(end, chevron, t::ICON_SM, t::TEXT_DIM);
+                    });
+                });
+            // Open: the field takes the accent as a focus ring.
+            field.border(1.0, if self.open { self.accent.get() } else { t::BORDER });
+            if !self.open {
+                return;
+            }
+            let visible = self.options.len().min(MAX_VISIBLE) as f32;
+            let mut list = c.col(());
+            list.w(Len::Fill)
+                .h_px(visible * OPTION_H + 2.0 * t::SP_1)
+                .pad(t::SP_1)
+                .radius(t::R_MD)
+                .rgba(t::PANEL_HI[0], t::PANEL_HI[1], t::PANEL_HI[2], 1.0)
+                .border(1.0, t::BORDER);
+            if self.options.len() > MAX_VISIBLE {
+                list.scroll_y();
+            }
+            list.child(|l| {
+                for (i, label) in self.options.iter().enumerate() {
+                    self.option(l, icons, i, label);
+                }
+            });
+        });
+    }
+
+    /// One option row; picking closes the list.
+    fn option(&self, s: &mut Scene, icons: &IconSet, i: usize, label: &str) {
+        let selected = i == self.selected;
+        let select = self.on_select.clone();
+        let toggle = self.on_toggle.clone();
+        s.row(())
+            .w(Len::Fill)
+            .h_px(OPTION_H)
+            .pad_xy(t::SP_3, t::SP_0)
+            .align(Align::Center)
+            .radius(t::R_SM)
+            .hover_color(t::HOVER_LIFT_SUBTLE)
+            .on_click(move |_| {
+                if !selected {
+                    select(i);
+                }
+                toggle();
+            })
+            .child(|r| {
+                r.text((), label, t::TEXT_SM).color(t::TEXT);
+                if selected {
+                    r.row(()).push_end().child(|end| {
+                        icons.render(end, Icon::Check, t::ICON_SM, self.accent.clone());
+                    });
+                }
+            });
+    }
+}
@@ -1,3 +1,14 @@
+//! Monochrome UI icons — the glyph set every button/row draws from.
+//!
+//! Icons ship as SVG sources (`assets/icons/*.svg`), rasterised once at
+//! startup into [`RASTER_PX`] textures and tinted at draw time via the
+//! node colour, so one asset covers every size, colour and DPI. This is
+//! deliberately not an icon font: a font would need its own glyph
+//! codepoint table + baseline fudging in every button, while the SVG path
+//! keeps icons as plain image nodes that size/align like any other box.
+//! Adding an icon = drop the SVG in, add the variant + `svg_bytes` arm,
+//! and extend [`ALL`].
+
 use std::collections::HashMap;
 
 use opal_gfx::{App, Bind, ImageHandle, Scene};
@@ -9,6 +20,8 @@ pub enum Icon {
     Menu,
     ChevronLeft,
     ChevronRight,
+    ChevronDown,
+    ChevronUp,
     Settings,
     Bell,
     Play,
@@ -29,6 +42,7 @@ pub enum Icon {
     Check,
     Queue,
     Devices,
+    Lyrics,
 }
 
 impl Icon {
@@ -37,6 +51,8 @@ impl Icon {
             Icon::Menu => include_bytes!("../../assets/icons/menu.svg"),
             Icon::ChevronLeft => include_bytes!("../../assets/icons/chevron-left.svg"),
             Icon::ChevronRight => include_bytes!("../../assets/icons/chevron-right.svg"),
+            Icon::ChevronDown => include_bytes!("../../assets/icons/chevron-down.svg"),
+            Icon::ChevronUp => include_bytes!("../../assets/icons/chevron-up.svg"),
             Icon::Settings => include_bytes!("../../assets/icons/settings.svg"),
             Icon::Bell => include_bytes!("../../assets/icons/bell.svg"),
             Icon::Play => include_bytes!("../../assets/icons/play.svg"),
@@ -57,14 +73,17 @@ impl Icon {
             Icon::Check => include_bytes!("../../assets/icons/check.svg"),
             Icon::Queue => include_bytes!("../../assets/icons/queue.svg"),
             Icon::Devices => include_bytes!("../../assets/icons/devices.svg"),
+            Icon::Lyrics => include_bytes!("../../assets/icons/lyrics.svg"),
         }
     }
 }
 
-const ALL: &[Icon] = &[
+pub(crate) const ALL: &[Icon] = &[
     Icon::Menu,
     Icon::ChevronLeft,
     Icon::ChevronRight,
+    Icon::ChevronDown,
+    Icon::ChevronUp,
     Icon::Settings,
     Icon::Bell,
     Icon::Play,
@@ -85,6 +104,7 @@ const ALL: &[Icon] = &[
     Icon::Check,
     Icon::Queue,
     Icon::Devices,
+    Icon::Lyrics,
 ];
 
 /// Raster size for the brand logo (gradient dragonfly). Larger than the
@@ -4,8 +4,10 @@
 //! - [`component`] — the `Component` trait every view region implements.
 //! - [`tokens`] — design tokens (spacing/radius/colours).
 //! - [`icon`] / [`splitter`] / [`chrome`] — input/layout primitives.
-//! - [`chip`] / [`thumb`] / [`crossfade`] / [`color`] — shared widgets +
-//!   colour helpers.
+//! - [`chip`] / [`toggle`] / [`dropdown`] / [`thumb`] / [`crossfade`] /
+//!   [`spinner`] / [`color`] — shared widgets + colour helpers.
+//! - [`surface`] / [`motion`] — glass vs solid panels and animated vs
+//!   instant tweens, per the effects level.
 
 pub mod button;
 pub mod chip;
@@ -13,7 +15,12 @@ pub mod chrome;
 pub mod color;
 pub mod component;
 pub mod crossfade;
+pub mod dropdown;
 pub mod icon;
+pub mod motion;
+pub mod spinner;
 pub mod splitter;
+pub mod surface;
 pub mod thumb;
+pub mod toggle;
 pub mod tokens;
@@ -0,0 +1,51 @@
+//! Motion level — whether the app's own tweens animate or land at once.
+//!
+//! Every tween the app starts takes its duration through [`dur`]. The
+//! frame tick sets the level once per frame ([`set_reduced`], from
+//! `--safe-mode` / low-power mode, the same switch as
+//! [`crate::widgets::surface`]); reduced, each duration is zero so the
+//! tween jumps straight to its target. Looping tweens (the spinner, the
+//! skeleton pulse) would spin the loop at zero length, so they check
+//! [`reduced`] and park instead.
+//!
+//! Timers that only keep the loop awake or time a label (toast and save
+//! anchors, the progress tween, "Copied") keep their real length — they
+//! aren't motion. The modal fades are timed inside opal-gfx's `Overlay`,
+//! which takes no duration from the app.
+
+use std::cell::Cell;
+use std::time::Duration;
+
+thread_local! {
+    static REDUCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Motion level for tweens started from here on.
+pub fn set_reduced(on: bool) {
+    REDUCED.with(|r| r.set(on));
+}
+
+/// Whether motion is reduced (tweens land at once).
+pub fn reduced() -> bool {
+    REDUCED.with(Cell::get)
+}
+
+/// `d`, or zero under reduced motion.
+pub fn dur(d: Duration) -> Duration {
+    if reduced() { Duration::ZERO } else { d }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduced_motion_zeroes_durations() {
+        let d = Duration::from_millis(260);
+        assert_eq!(dur(d), d);
+        set_reduced(true);
+        assert_eq!(dur(d), Duration::ZERO);
+        set_reduced(false);
+        assert_eq!(dur(d), d);
+    }
+}
@@ -0,0 +1,141 @@
+//! Loading indicators drawn as a ring of dots — the indeterminate
+//! [`spinner`] (a bright head chasing round the ring with a fading tail)
+//! and the determinate [`progress_ring`] (dots fill clockwise from 12
+//! o'clock as a 0..=1 value grows).
+//!
+//! Dots rather than an arc: each is a rounded rect with its own opacity
+//! bind, so both indicators are plain scene nodes — no shader, no
+//! rotation, no rebuild while they animate. Every visible spinner reads
+//! one shared [`Spin`] clock, which the frame tick keeps running only
+//! while something is loading.
+
+use std::cell::Cell;
+use std::f32::consts::TAU;
+use std::time::{Duration, Instant};
+
+use opal_gfx::{Computed, Curve, Scene, Signal, Timeline};
+
+use crate::widgets::motion;
+use crate::widgets::tokens as t;
+
+/// Dots in the ring.
+const DOTS: usize = 8;
+/// Dot diameter as a fraction of the ring's size.
+const DOT_FRAC: f32 = 0.2;
+/// Opacity of an unlit dot (the tail end / unfilled part of the ring).
+const DIM: f32 = 0.2;
+/// One revolution of the spinner head.
+const PERIOD: Duration = Duration::from_millis(900);
+
+/// The shared spinner clock: a phase whose fractional part is the head's
+/// position round the ring (0 = 12 o'clock, clockwise).
+pub struct Spin {
+    phase: Signal<f32>,
+    on: Cell<bool>,
+}
+
+impl Spin {
+    pub fn new() -> Self {
+        Self {
+            phase: Signal::new(0.0),
+            on: Cell::new(false),
+        }
+    }
+
+    /// Per-frame: keep the ring turning while `active`, park it otherwise
+    /// (and under reduced motion, where the ring holds still). The tween always runs a full turn past the wrap point and is
+    /// shifted back by one turn as it crosses it, so it is never allowed
+    /// to finish — the loop can't go idle between revolutions.
+    pub fn tick(&self, active: bool, tl: &mut Timeline, now: Instant) {
+        if !active || motion::reduced() {
+            if self.on.replace(false) {
+                tl.stop_for(&self.phase);
+                self.phase.set(0.0);
+            }
+            return;
+        }
+        let p = self.phase.get();
+        if self.on.replace(true) && p < 1.0 {
+            return;
+        }
+        let p = p.fract();
+        self.phase.set(p);
+        let left = PERIOD.mul_f32(2.0 - p);
+        tl.animate(&self.phase, 2.0, Curve::Linear, left, now);
+    }
+}
+
+impl Default for Spin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The indeterminate spinner, `size` px square, driven by `spin`.
+pub fn spinner(s: &mut Scene, spin: &Spin, size: f32, color: [f32; 4]) {
+    ring(s, size, color, |i| {
+        Computed::new((spin.phase.clone(),), move |(p,)| spin_opacity(p, i))
+    });
+}
+
+/// A determinate progress ring, `size` px square, filled to `value`
+/// (0..=1; read live, so progress updates need no rebuild). The playlist
+/// picker shows one while the membership index scans.
+pub fn progress_ring(s: &mut Scene, value: &Signal<f32>, size: f32, color: [f32; 4]) {
+    ring(s, size, color, |i| {
+        Computed::new((value.clone(),), move |(v,)| fill_opacity(v, i))
+    });
+}
+
+/// Lay `DOTS` dots out round a `size` px ring, dot `i` faded by
+/// `opacity(i)`.
+fn ring(s: &mut Scene, size: f32, color: [f32; 4], opacity: impl Fn(usize) -> Computed<f32>) {
+    let dot = size * DOT_FRAC;
+    let r = (size - dot) / 2.0;
+    s.col(()).w_px(size).h_px(size).child(|c| {
+        for i in 0..DOTS {
+            let a = i as f32 / DOTS as f32 * TAU;
+            c.rect(())
+                .abs(r + r * a.sin(), r - r * a.cos())
+                .w_px(dot)
+                .h_px(dot)
+                .radius(t::R_FULL)
+                .color(color)
+                .opacity_bind(opacity(i));
+        }
+    });
+}
+
+/// Dot `i` under a head at `phase`: full at the head, fading over the
+/// turn behind it.
+fn spin_opacity(phase: f32, i: usize) -> f32 {
+    let behind = (phase - i as f32 / DOTS as f32).rem_euclid(1.0);
+    DIM + (1.0 - DIM) * (1.0 - behind)
+}
+
+/// Dot `i` at progress `value`: lit once the fill passes it, with the
+/// dot at the leading edge partly lit.
+fn fill_opacity(value: f32, i: usize) -> f32 {
+    let lit = (value.clamp(0.0, 1.0) * DOTS as f32 - i as f32).clamp(0.0, 1.0);
+    DIM + (1.0 - DIM) * lit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_is_brightest_and_ring_fills_clockwise() {
+        // Head on dot 2: dot 2 full, dot 1 (just passed) next, dot 3
+        // (a whole turn behind) dimmest.
+        let phase = 2.0 / DOTS as f32;
+        assert_eq!(spin_opacity(phase, 2), 1.0);
+        assert!(spin_opacity(phase, 1) > spin_opacity(phase, 3));
+
+        assert!((0..DOTS).all(|i| fill_opacity(0.0, i) == DIM));
+        assert!((0..DOTS).all(|i| fill_opacity(1.0, i) == 1.0));
+        let half: Vec<_> = (0..DOTS).map(|i| fill_opacity(0.5, i)).collect();
+        assert_eq!(half[DOTS / 2 - 1], 1.0);
+        assert_eq!(half[DOTS / 2], DIM);
+    }
+}
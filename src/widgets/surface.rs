@@ -0,0 +1,66 @@
+//! Effect-dependent surfaces — the one place that decides whether a
+//! panel is frosted glass or a plain fill.
+//!
+//! Views ask for a [`frosted`] panel or an [`ambient`] backdrop and never
+//! look at the effects level themselves. The host sets it once per scene
+//! build ([`set_reduced`], from `--safe-mode` / low-power mode); under
+//! reduced effects glass becomes a near-opaque solid (no backdrop pass)
+//! and the ambient album-art stack isn't emitted at all.
+
+use std::cell::Cell;
+
+use opal_gfx::{Computed, Len, NodeBuilderRef, Scene, Signal};
+
+use crate::widgets::color::{lerp4, with_alpha};
+use crate::widgets::tokens as t;
+
+thread_local! {
+    static REDUCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Effects level for the surfaces built from here on. Set before each
+/// build; a flip needs a rebuild to take effect.
+pub fn set_reduced(on: bool) {
+    REDUCED.with(|r| r.set(on));
+}
+
+fn reduced() -> bool {
+    REDUCED.with(Cell::get)
+}
+
+/// A panel over scrolling content, tinted `rgb`: glass blurring what's
+/// beneath at `alpha`, or — reduced — a plain panel near-opaque enough
+/// that the content beneath can't bleed through its labels.
+pub fn frosted<'s>(s: &'s mut Scene, blur: f32, rgb: [f32; 3], alpha: f32) -> NodeBuilderRef<'s> {
+    if reduced() {
+        let mut panel = s.col(());
+        panel.rgba(rgb[0], rgb[1], rgb[2], 0.96);
+        panel
+    } else {
+        let mut glass = s.glass(());
+        glass.blur(blur).rgba(rgb[0], rgb[1], rgb[2], alpha);
+        glass
+    }
+}
+
+/// Full-window background fill `name` with the ambient stack `layers`
+/// (cover layers, glass) over it. Reduced: `layers` is skipped and the
+/// fill is the whole background, tinted a touch toward `accent` so the
+/// track still colours the window without any texture sampling.
+pub fn ambient(
+    s: &mut Scene,
+    name: &'static str,
+    accent: &Signal<[f32; 4]>,
+    layers: impl FnOnce(&mut Scene),
+) {
+    let mut bg = s.rect(name);
+    bg.abs(0.0, 0.0).w(Len::Fill).h(Len::Fill);
+    if reduced() {
+        bg.color(Computed::new((accent.clone(),), |(a,)| {
+            with_alpha(lerp4(t::BG, a, 0.12), 1.0)
+        }));
+        return;
+    }
+    bg.rgba(t::BG[0], t::BG[1], t::BG[2], 1.0);
+    layers(s);
+}
@@ -0,0 +1,118 @@
+//! Boolean controls — the animated on/off switch and the check box.
+//!
+//! Both take the live accent so the "on" state picks up the album palette
+//! like the chips and the play pill. A `disabled` control renders dimmed
+//! and ignores clicks.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use opal_gfx::{Align, Computed, Curve, Scene, Signal};
+
+use crate::widgets::color::lerp4;
+use crate::widgets::icon::{Icon, IconSet};
+use crate::widgets::motion;
+use crate::widgets::tokens as t;
+
+// Animated switch dimensions (logical px). The knob slides `TRAVEL` px
+// between the two pad-inset ends of the track.
+const SWITCH_W: f32 = 44.0;
+const SWITCH_H: f32 = 24.0;
+const SWITCH_KNOB: f32 = 18.0;
+const SWITCH_PAD: f32 = 3.0;
+const SWITCH_TRAVEL: f32 = SWITCH_W - SWITCH_KNOB - 2.0 * SWITCH_PAD;
+/// Off-state track colour — a faint white so the switch reads as a
+/// recessed pill before it lights up to the accent.
+const SWITCH_OFF: [f32; 4] = [1.0, 1.0, 1.0, 0.18];
+/// Track + knob tween — snappy enough to feel responsive, slow enough to
+/// read as motion.
+const SWITCH_MS: u64 = 160;
+/// Opacity of a disabled control.
+const DISABLED_OPACITY: f32 = 0.4;
+
+/// The animated on/off switch. A `knob_t` signal (0..=TRAVEL px) is
+/// **seeded to the current state** at build so opening the popup shows
+/// the right position instantly — no spurious mount animation. Clicking
+/// flips the bound `state`, reports the new value to `on_change` and
+/// tweens `knob_t` via the timeline; the knob (spacer-width bind) and
+/// track colour (`Computed` over `knob_t`) both follow, so the slide +
+/// colour fade are one smooth motion with no scene rebuild. The lib
+/// bubbles a click on the knob up to this handler.
+pub fn toggle_switch(
+    s: &mut Scene,
+    state: &Signal<bool>,
+    accent: &Signal<[f32; 4]>,
+    disabled: bool,
+    on_change: Rc<dyn Fn(bool)>,
+) {
+    let knob_t = Signal::new(if state.get() { SWITCH_TRAVEL } else { 0.0 });
+    let track_col = Computed::new((knob_t.clone(), accent.clone()), |(x, acc)| {
+        let f = (x / SWITCH_TRAVEL).clamp(0.0, 1.0);
+        lerp4(SWITCH_OFF, acc, f)
+    });
+    let mut track = s.row(());
+    track
+        .w_px(SWITCH_W)
+        .h_px(SWITCH_H)
+        .radius(t::R_FULL)
+        .color(track_col)
+        .align(Align::Center)
+        .pad_xy(SWITCH_PAD, 0.0);
+    if disabled {
+        track.opacity(DISABLED_OPACITY);
+    } else {
+        let st = state.clone();
+        let kt = knob_t.clone();
+        track.on_click(move |ctx| {
+            let now_on = !st.get();
+            st.set(now_on);
+            on_change(now_on);
+            let target = if now_on { SWITCH_TRAVEL } else { 0.0 };
+            ctx.timeline.animate(
+                &kt,
+                target,
+                Curve::EaseInOut,
+                motion::dur(Duration::from_millis(SWITCH_MS)),
+                ctx.now,
+            );
+        });
+    }
+    track.child(|tr| {
+        // Spacer whose width tracks `knob_t` (0 → TRAVEL), pushing the
+        // knob from the left end to the right as the tween advances.
+        tr.rect(())
+            .width_px_bind(knob_t.clone())
+            .h_px(1.0)
+            .rgba(0.0, 0.0, 0.0, 0.0);
+        tr.rect(())
+            .w_px(SWITCH_KNOB)
+            .h_px(SWITCH_KNOB)
+            .radius(t::R_FULL)
+            .rgba(1.0, 1.0, 1.0, 1.0);
+    });
+}
+
+/// A check box: accent-filled with a check glyph when `on`, an empty
+/// bordered box when off. Visual only — callers usually make the whole
+/// row the click target (a box is a small hit area) and rebuild or flip
+/// `on` from their own state.
+pub fn checkbox(
+    s: &mut Scene,
+    icons: &IconSet,
+    on: bool,
+    accent: &Signal<[f32; 4]>,
+    disabled: bool,
+) {
+    let mut b = s.row(());
+    b.w_px(t::SP_5).h_px(t::SP_5).center().radius(t::R_SM);
+    if on {
+        b.color(accent.clone()).child(|b| {
+            icons.render(b, Icon::Check, t::ICON_SM, t::PANEL);
+        });
+    } else {
+        b.border(1.5, t::TEXT_DIM);
+    }
+    if disabled {
+        b.opacity(DISABLED_OPACITY);
+    }
+}
@@ -103,6 +103,8 @@ pub enum WorkerCommand {
         /// Persisted "normalize volume" preference → librespot
         /// normalisation + limiter.
         normalize: bool,
+        /// Persisted output device name (`None` = system default).
+        output_device: Option<String>,
     },
     /// Transport control on the active Connect device. `local` (Opal is
     /// the active device) drives our own Spirc directly — instant + reliable;
@@ -143,6 +145,9 @@ pub enum WorkerCommand {
     FetchDevices {
         access_token: String,
     },
+    /// Enumerate local audio outputs for the settings device picker.
+    /// Blocking (ALSA/Pulse probing can take a while), so off the UI thread.
+    ListOutputDevices,
     /// Transfer playback to a device (and resume there). `position_ms` is
     /// `Some` only when leaving Opal itself: the Web API transfer drops
     /// our librespot device's position (the target restarts at 0:00), so we
@@ -167,6 +172,18 @@ pub enum WorkerCommand {
     FetchQueue {
         access_token: String,
     },
+    /// Lyrics for the lyrics page, from LRCLIB (disk-cached per track).
+    FetchLyrics {
+        track_id: String,
+        title: String,
+        artist: String,
+        duration_ms: u64,
+    },
+    /// Catalogue search for the search page (debounced by the caller).
+    Search {
+        access_token: String,
+        query: String,
+    },
     /// Append a track to the active device's queue (right-click → Add to
     /// queue). Works on remote devices too.
     AddToQueue {
@@ -192,6 +209,14 @@ pub enum WorkerCommand {
         track_uri: String,
         add: bool,
     },
+    /// Launch warm-up: prefetch the marked playlists' metadata, first
+    /// track page and first `artworks` covers into the disk cache. No
+    /// response — a later open simply hits the cache.
+    WarmPlaylists {
+        access_token: String,
+        ids: Vec<String>,
+        artworks: u32,
+    },
 }
 
 /// A transport intent dispatched from a player-bar button. Resolved to
@@ -240,6 +265,10 @@ pub enum WorkerResponse {
     Devices {
         devices: Vec<api::Device>,
     },
+    /// Local audio output names (settings device picker).
+    OutputDevices {
+        names: Vec<String>,
+    },
     /// The cluster's active device changed; `is_self` = Opal is it.
     ActiveDeviceChanged {
         device_id: String,
@@ -261,6 +290,23 @@ pub enum WorkerResponse {
     QueueLoaded {
         tracks: Vec<api::PlaylistTrack>,
     },
+    /// Lyrics lookup finished for `track_id`: `Ok(None)` = no match,
+    /// `Err` = the lookup itself failed (worth retrying).
+    LyricsLoaded {
+        track_id: String,
+        lyrics: Result<Option<crate::lyrics::Lyrics>, String>,
+    },
+    /// Results for `query` (the reducer drops them if the field has moved
+    /// on since).
+    SearchLoaded {
+        query: String,
+        results: api::SearchResults,
+    },
+    /// The search request for `query` failed.
+    SearchFailed {
+        query: String,
+        error: String,
+    },
     OAuthStarted {
         auth_url: String,
     },
@@ -362,6 +408,12 @@ pub enum WorkerResponse {
     MembershipLoaded {
         playlists: Vec<crate::model::membership::MembershipPlaylist>,
     },
+    /// The index scan got through `scanned` of `total` playlists — drives
+    /// the picker's progress ring until `MembershipLoaded`.
+    MembershipProgress {
+        scanned: usize,
+        total: usize,
+    },
     /// Which playlists the given track is in (answer to `QueryMembership`).
     TrackMembership {
         track_uri: String,
@@ -379,6 +431,9 @@ pub enum WorkerResponse {
 pub struct Worker {
     cmd_tx: UnboundedSender<WorkerCommand>,
     resp_rx: Receiver<WorkerResponse>,
+    /// Cover art (and its accent) is fetched at all — off under
+    /// `--safe-mode`, which leaves every cover on its placeholder.
+    images: bool,
 }
 
 #[derive(Clone)]
@@ -395,7 +450,7 @@ impl Responder {
 }
 
 impl Worker {
-    pub fn new(wake: Arc<WakeHandle>, uploader: Arc<Uploader>) -> Self {
+    pub fn new(wake: Arc<WakeHandle>, uploader: Arc<Uploader>, images: bool) -> Self {
         let (cmd_tx, mut cmd_rx) = tmpsc::unbounded_channel::<WorkerCommand>();
         let (resp_tx, resp_rx): (Sender<WorkerResponse>, Receiver<WorkerResponse>) = channel();
         let resp = Responder { tx: resp_tx, wake };
@@ -472,6 +527,7 @@ impl Worker {
                             initial_volume,
                             quality,
                             normalize,
+                            output_device,
                         } => spawn_connect_session(
                             resp.clone(),
                             session.clone(),
@@ -480,6 +536,7 @@ impl Worker {
                             initial_volume,
                             quality,
                             normalize,
+                            output_device,
                         ),
                         WorkerCommand::Playback {
                             access_token,
@@ -514,6 +571,7 @@ impl Worker {
                         WorkerCommand::FetchDevices { access_token } => {
                             spawn_fetch_devices(resp.clone(), access_token)
                         }
+                        WorkerCommand::ListOutputDevices => spawn_list_output_devices(resp.clone()),
                         WorkerCommand::TransferPlayback {
                             access_token,
                             device_id,
@@ -531,6 +589,16 @@ impl Worker {
                         WorkerCommand::FetchQueue { access_token } => {
                             spawn_fetch_queue(resp.clone(), access_token)
                         }
+                        WorkerCommand::Search {
+                            access_token,
+                            query,
+                        } => spawn_search(resp.clone(), access_token, query),
+                        WorkerCommand::FetchLyrics {
+                            track_id,
+                            title,
+                            artist,
+                            duration_ms,
+                        } => spawn_fetch_lyrics(resp.clone(), track_id, title, artist, duration_ms),
                         WorkerCommand::AddToQueue { access_token, uri } => {
                             tokio::spawn(async move {
                                 if let Err(e) = api::add_to_queue(&access_token, &uri).await {
@@ -557,12 +625,21 @@ impl Worker {
                             track_uri,
                             add,
                         ),
+                        WorkerCommand::WarmPlaylists {
+                            access_token,
+                            ids,
+                            artworks,
+                        } => spawn_warm_playlists(access_token, ids, artworks),
                     }
                 }
             });
         });
 
-        Self { cmd_tx, resp_rx }
+        Self {
+            cmd_tx,
+            resp_rx,
+            images,
+        }
     }
 
     pub fn start_oauth(&self, client_id: String) {
@@ -603,9 +680,15 @@ impl Worker {
             .send(WorkerCommand::FetchArtist { access_token, id });
     }
     pub fn fetch_album_art(&self, url: String, key: String) {
+        if !self.images {
+            return;
+        }
         let _ = self.cmd_tx.send(WorkerCommand::FetchAlbumArt { url, key });
     }
     pub fn fetch_accent(&self, image_hex: String) {
+        if !self.images {
+            return;
+        }
         let _ = self.cmd_tx.send(WorkerCommand::FetchAccent { image_hex });
     }
     pub fn fetch_canvas(&self, track_uri: String, track_id: String) {
@@ -620,12 +703,14 @@ impl Worker {
         initial_volume: f32,
         quality: crate::prefs::AudioQuality,
         normalize: bool,
+        output_device: Option<String>,
     ) {
         let _ = self.cmd_tx.send(WorkerCommand::ConnectSpotifySession {
             access_token,
             initial_volume,
             quality,
             normalize,
+            output_device,
         });
     }
     pub fn skip_forward(&self, access_token: String, count: u32, local: bool) {
@@ -662,6 +747,9 @@ impl Worker {
     pub fn fetch_devices(&self, access_token: String) {
         let _ = self.cmd_tx.send(WorkerCommand::FetchDevices { access_token });
     }
+    pub fn list_output_devices(&self) {
+        let _ = self.cmd_tx.send(WorkerCommand::ListOutputDevices);
+    }
     pub fn transfer_playback(
         &self,
         access_token: String,
@@ -690,6 +778,20 @@ impl Worker {
     pub fn fetch_queue(&self, access_token: String) {
         let _ = self.cmd_tx.send(WorkerCommand::FetchQueue { access_token });
     }
+    pub fn fetch_lyrics(&self, track_id: String, title: String, artist: String, duration_ms: u64) {
+        let _ = self.cmd_tx.send(WorkerCommand::FetchLyrics {
+            track_id,
+            title,
+            artist,
+            duration_ms,
+        });
+    }
+    pub fn search(&self, access_token: String, query: String) {
+        let _ = self.cmd_tx.send(WorkerCommand::Search {
+            access_token,
+            query,
+        });
+    }
     pub fn add_to_queue(&self, access_token: String, uri: String) {
         let _ = self
             .cmd_tx
@@ -717,6 +819,13 @@ impl Worker {
             add,
         });
     }
+    pub fn warm_playlists(&self, access_token: String, ids: Vec<String>, artworks: u32) {
+        let _ = self.cmd_tx.send(WorkerCommand::WarmPlaylists {
+            access_token,
+            ids,
+            artworks,
+        });
+    }
     pub fn poll(&self) -> Option<WorkerResponse> {
         self.resp_rx.try_recv().ok()
     }
@@ -1519,6 +1628,95 @@ fn spawn_fetch_playlist(resp: Responder, access_token: String, id: String, liked
     });
 }
 
+/// Let the launch burst (home feed, player seed, session connect, the
+/// restored cover) settle before warm-up starts competing for bandwidth.
+const WARMUP_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Background warm-up of marked playlists. Deliberately low priority:
+/// starts after [`WARMUP_DELAY`], works through one playlist and one
+/// cover at a time, and skips anything already on disk. Only the first
+/// track page is fetched — it rides the API JSON cache, so an open shows
+/// the list at once while the rest streams in as usual. Failures are
+/// logged and skipped; the warm-up is purely an optimisation.
+fn spawn_warm_playlists(access_token: String, ids: Vec<String>, artworks: u32) {
+    tokio::spawn(async move {
+        tokio::time::sleep(WARMUP_DELAY).await;
+        for id in ids {
+            let liked = id == api::LIKED_SONGS_ID;
+            let key = id.clone();
+            let cached = tokio::task::spawn_blocking(move || {
+                disk_cache::read_json::<api::PlaylistDetail>(&key, PLAYLIST_DISK_TTL)
+            })
+            .await
+            .ok()
+            .flatten();
+            let mut covers: Vec<String> = Vec::new();
+            let tracks = match cached {
+                Some(detail) => {
+                    covers.extend(detail.image_url);
+                    detail.tracks
+                }
+                None => {
+                    if !liked {
+                        match api::playlist_meta(&access_token, &id).await {
+                            Ok(m) => covers.extend(m.image_url),
+                            Err(e) => {
+                                warn!("warm-up: playlist_meta({id}) failed: {e}");
+                                continue;
+                            }
+                        }
+                    }
+                    let url = if liked {
+                        api::liked_tracks_url(0, api::LIKED_PAGE)
+                    } else {
+                        api::playlist_tracks_url(&id, 0, api::PLAYLIST_PAGE)
+                    };
+                    match api::fetch_tracks_page(&access_token, &url).await {
+                        Ok(page) => page.tracks,
+                        Err(e) => {
+                            warn!("warm-up: first page of {id} failed: {e}");
+                            continue;
+                        }
+                    }
+                }
+            };
+            // Row covers in track order, deduped (albums repeat in a list).
+            let mut budget = artworks as usize;
+            for url in tracks.into_iter().filter_map(|t| t.album_image_url) {
+                if budget == 0 {
+                    break;
+                }
+                if !covers.contains(&url) {
+                    covers.push(url);
+                    budget -= 1;
+                }
+            }
+            let mut fetched = 0usize;
+            for url in covers {
+                let key = album_art::cache_key(&url);
+                let k = key.clone();
+                let hit = tokio::task::spawn_blocking(move || disk_cache::path(&k).is_some())
+                    .await
+                    .unwrap_or(false);
+                if hit {
+                    continue;
+                }
+                let bytes = {
+                    let _permit = art_throttle().acquire().await.ok();
+                    fetch_art_bytes(&url).await
+                };
+                if let Some(bytes) = bytes {
+                    tokio::task::spawn_blocking(move || disk_cache::write(&key, &bytes))
+                        .await
+                        .ok();
+                    fetched += 1;
+                }
+            }
+            info!("warm-up: playlist {id} ready ({fetched} covers fetched)");
+        }
+    });
+}
+
 /// Global cap on concurrent album-art network fetches. Spotify's CDN
 /// generally tolerates parallel requests, but a full Home view can
 /// kick off 30–50 covers at once; throttling keeps us friendly + means
@@ -1643,6 +1841,7 @@ fn spawn_fetch_album_art(resp: Responder, uploader: Arc<Uploader>, url: String,
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_connect_session(
     resp: Responder,
     session_slot: Arc<AsyncMutex<Option<Session>>>,
@@ -1651,13 +1850,23 @@ fn spawn_connect_session(
     initial_volume: f32,
     quality: crate::prefs::AudioQuality,
     normalize: bool,
+    output_device: Option<String>,
 ) {
     tokio::spawn(async move {
         let s = spotify_session::new_session();
         *session_slot.lock().await = Some(s.clone());
 
         let creds = Credentials::with_access_token(access_token);
-        let boot = match spirc_bootstrap::start(s, creds, initial_volume, quality, normalize).await {
+        let boot = match spirc_bootstrap::start(
+            s,
+            creds,
+            initial_volume,
+            quality,
+            normalize,
+            output_device,
+        )
+        .await
+        {
             Ok(b) => b,
             Err(e) => {
                 error!("spirc bootstrap failed: {e}");
@@ -1787,6 +1996,15 @@ fn spawn_fetch_devices(resp: Responder, access_token: String) {
     });
 }
 
+fn spawn_list_output_devices(resp: Responder) {
+    tokio::spawn(async move {
+        match tokio::task::spawn_blocking(spirc_bootstrap::output_devices).await {
+            Ok(names) => resp.send(WorkerResponse::OutputDevices { names }),
+            Err(e) => warn!("listing audio outputs panicked: {e}"),
+        }
+    });
+}
+
 fn spawn_transfer(access_token: String, device_id: String, position_ms: Option<u32>) {
     tokio::spawn(async move {
         // The cluster push after the transfer is the UI's confirmation.
@@ -1860,6 +2078,79 @@ fn spawn_fetch_queue(resp: Responder, access_token: String) {
     });
 }
 
+/// Results per section on the search page.
+const SEARCH_LIMIT: u32 = 10;
+
+fn spawn_search(resp: Responder, access_token: String, query: String) {
+    tokio::spawn(async move {
+        match api::search(&access_token, &query, SEARCH_LIMIT).await {
+            Ok(results) => resp.send(WorkerResponse::SearchLoaded { query, results }),
+            Err(e) => {
+                warn!("search({query}) failed: {e}");
+                resp.send(WorkerResponse::SearchFailed {
+                    query,
+                    error: e.to_string(),
+                });
+            }
+        }
+    });
+}
+
+/// Per-track lyrics lookup result persisted to the JSON disk cache. A
+/// `None` is a negative entry — "LRCLIB has nothing for this track" — so
+/// reopening the page on it doesn't re-query. Keyed by `lyrics_<track_id>`.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CachedLyrics {
+    lyrics: Option<crate::lyrics::Lyrics>,
+}
+
+/// How long a lookup stays valid. Lyrics for a recording rarely change;
+/// the bound lets a missing entry pick up a later LRCLIB submission.
+const LYRICS_TTL: std::time::Duration = std::time::Duration::from_secs(60 * 60 * 24 * 7);
+
+fn spawn_fetch_lyrics(
+    resp: Responder,
+    track_id: String,
+    title: String,
+    artist: String,
+    duration_ms: u64,
+) {
+    tokio::spawn(async move {
+        let key = format!("lyrics_{track_id}");
+        let cached = tokio::task::spawn_blocking({
+            let key = key.clone();
+            move || disk_cache::read_json::<CachedLyrics>(&key, LYRICS_TTL)
+        })
+        .await
+        .ok()
+        .flatten();
+        if let Some(hit) = cached {
+            debug!("lyrics cache hit {track_id}");
+            resp.send(WorkerResponse::LyricsLoaded {
+                track_id,
+                lyrics: Ok(hit.lyrics),
+            });
+            return;
+        }
+        let lyrics = match crate::lyrics::fetch(&title, &artist, duration_ms).await {
+            Ok(lyrics) => {
+                // Only a definite answer is cached — a network error retries
+                // on the next open.
+                let entry = CachedLyrics {
+                    lyrics: lyrics.clone(),
+                };
+                tokio::task::spawn_blocking(move || disk_cache::write_json(&key, &entry));
+                Ok(lyrics)
+            }
+            Err(e) => {
+                warn!("lyrics lookup failed ({title} — {artist}): {e}");
+                Err(e.to_string())
+            }
+        };
+        resp.send(WorkerResponse::LyricsLoaded { track_id, lyrics });
+    });
+}
+
 /// Disk-cache key for the playlist-membership index snapshot.
 const MEMBERSHIP_KEY: &str = "playlist_membership";
 /// How many playlists to scan concurrently when (re)building the index.
@@ -1927,6 +2218,8 @@ fn spawn_load_membership(resp: Responder, membership: MembershipArc, access_toke
         info!("membership: scanning {} editable playlists", editable.len());
         let mut index: std::collections::HashMap<String, Vec<String>> =
             std::collections::HashMap::new();
+        let total = editable.len();
+        let mut scanned = 0;
         for chunk in editable.chunks(MEMBERSHIP_SCAN_CONCURRENCY) {
             let futs = chunk.iter().map(|p| {
                 let token = access_token.clone();
@@ -1943,6 +2236,8 @@ fn spawn_load_membership(resp: Responder, membership: MembershipArc, access_toke
                     Err(e) => warn!("membership: scan playlist {id} failed: {e}"),
                 }
             }
+            scanned += chunk.len();
+            resp.send(WorkerResponse::MembershipProgress { scanned, total });
         }
         let playlists: Vec<MembershipPlaylist> = editable
             .into_iter()